@@ -55,7 +55,8 @@ module Bridge
               if ["f32", "f64"].include?(scalar)
                 typecode = { "f32" => "f", "f64" => "d" }.fetch(scalar)
 
-                o.block("extern", pad: true) do
+                o.puts("#[cfg(not(feature = \"portable\"))]", pad: true)
+                o.block("extern") do
                   o.puts("fn __invert_#{typecode}#{i}(a: #{name}) -> #{name};")
                 end
               end
@@ -79,12 +80,23 @@ module Bridge
               end
             end
 
-            o.block("impl std::ops::Mul<#{transpose_name}> for #{name}", pad: true) do |o|
-              o.puts("type Output = #{type}#{i}x#{i};")
+            o.block("impl std::ops::Neg for #{name}", pad: true) do |o|
+              o.puts("type Output = Self;")
               o.puts
               o.puts("#[inline(always)]")
-              o.block("fn mul(self, other: #{transpose_name}) -> Self::Output") do |o|
-                o.puts("return self.dot(other);")
+              o.block("fn neg(self) -> Self") do |o|
+                o.puts("return #{name}(#{j.times.map { |k| "-self.#{k}" }.join(", ")});")
+              end
+            end
+
+            (2 .. max_matrix_size).each do |k|
+              o.block("impl std::ops::Mul<#{type}#{k}x#{j}> for #{name}", pad: true) do |o|
+                o.puts("type Output = #{type}#{k}x#{i};")
+                o.puts
+                o.puts("#[inline(always)]")
+                o.block("fn mul(self, other: #{type}#{k}x#{j}) -> Self::Output") do |o|
+                  o.puts("return self.dot(other);")
+                end
               end
             end
 
@@ -108,12 +120,103 @@ module Bridge
               end
             end
 
-            o.block("impl Dot<#{transpose_name}> for #{name}", pad: true) do |o|
-              o.puts("type DotProduct = #{type}#{i}x#{i};")
+            o.block("impl std::ops::Div<#{scalar}> for #{name}", pad: true) do |o|
+              o.puts("type Output = Self;")
+              o.puts
+              o.puts("#[inline(always)]")
+              o.block("fn div(self, other: #{scalar}) -> Self") do |o|
+                o.puts("let a = #{vector_name}::broadcast(other);")
+                o.puts
+                o.puts("return #{name}(#{j.times.map { |k| "self.#{k} / a" }.join(", ")});")
+              end
+            end
+
+            o.block("impl std::ops::Mul<#{name}> for #{vector_name}", pad: true) do |o|
+              o.puts("type Output = #{transpose_vector_name};")
               o.puts
               o.puts("#[inline(always)]")
-              o.block("fn dot(self, other: #{transpose_name}) -> Self::DotProduct") do |o|
-                o.puts("return #{type}#{i}x#{i}(#{i.times.map { |k| "self.dot(other.#{k})" }.join(", ")});")
+              o.block("fn mul(self, other: #{name}) -> Self::Output") do |o|
+                o.puts("return other.transpose().dot(self);")
+              end
+            end
+
+            o.block("impl std::ops::AddAssign for #{name}", pad: true) do |o|
+              o.puts("#[inline(always)]")
+              o.block("fn add_assign(&mut self, other: Self)") do |o|
+                o.puts("*self = *self + other;")
+              end
+            end
+
+            o.block("impl std::ops::SubAssign for #{name}", pad: true) do |o|
+              o.puts("#[inline(always)]")
+              o.block("fn sub_assign(&mut self, other: Self)") do |o|
+                o.puts("*self = *self - other;")
+              end
+            end
+
+            if i == j
+              o.block("impl std::ops::MulAssign for #{name}", pad: true) do |o|
+                o.puts("#[inline(always)]")
+                o.block("fn mul_assign(&mut self, other: Self)") do |o|
+                  o.puts("*self = *self * other;")
+                end
+              end
+            end
+
+            o.block("impl std::ops::MulAssign<#{scalar}> for #{name}", pad: true) do |o|
+              o.puts("#[inline(always)]")
+              o.block("fn mul_assign(&mut self, other: #{scalar})") do |o|
+                o.puts("*self = *self * other;")
+              end
+            end
+
+            o.block("impl std::ops::DivAssign<#{scalar}> for #{name}", pad: true) do |o|
+              o.puts("#[inline(always)]")
+              o.block("fn div_assign(&mut self, other: #{scalar})") do |o|
+                o.puts("*self = *self / other;")
+              end
+            end
+
+            zero = (["#{vector_name}::from(0)"] * j).join(", ")
+
+            o.block("impl std::iter::Sum for #{name}", pad: true) do |o|
+              o.puts("#[inline]")
+              o.block("fn sum<I: Iterator<Item=#{name}>>(iter: I) -> #{name}") do |o|
+                o.puts("return iter.fold(#{name}(#{zero}), |a, b| a + b);")
+              end
+            end
+
+            o.block("impl<'a> std::iter::Sum<&'a #{name}> for #{name}", pad: true) do |o|
+              o.puts("#[inline]")
+              o.block("fn sum<I: Iterator<Item=&'a #{name}>>(iter: I) -> #{name}") do |o|
+                o.puts("return iter.fold(#{name}(#{zero}), |a, b| a + *b);")
+              end
+            end
+
+            if i == j
+              o.block("impl std::iter::Product for #{name}", pad: true) do |o|
+                o.puts("#[inline]")
+                o.block("fn product<I: Iterator<Item=#{name}>>(iter: I) -> #{name}") do |o|
+                  o.puts("return iter.fold(#{name}::identity(), |a, b| a * b);")
+                end
+              end
+
+              o.block("impl<'a> std::iter::Product<&'a #{name}> for #{name}", pad: true) do |o|
+                o.puts("#[inline]")
+                o.block("fn product<I: Iterator<Item=&'a #{name}>>(iter: I) -> #{name}") do |o|
+                  o.puts("return iter.fold(#{name}::identity(), |a, b| a * *b);")
+                end
+              end
+            end
+
+            (2 .. max_matrix_size).each do |l|
+              o.block("impl Dot<#{type}#{l}x#{j}> for #{name}", pad: true) do |o|
+                o.puts("type DotProduct = #{type}#{l}x#{i};")
+                o.puts
+                o.puts("#[inline(always)]")
+                o.block("fn dot(self, other: #{type}#{l}x#{j}) -> Self::DotProduct") do |o|
+                  o.puts("return #{type}#{l}x#{i}(#{l.times.map { |k| "self.dot(other.#{k})" }.join(", ")});")
+                end
               end
             end
 
@@ -133,6 +236,154 @@ module Bridge
               end
             end
 
+            o.block("impl std::ops::Index<usize> for #{name}", pad: true) do |o|
+              o.puts("type Output = #{vector_name};")
+              o.puts
+              o.puts("#[inline]", pad: true)
+              o.block("fn index(&self, index: usize) -> &#{vector_name}") do |o|
+                o.block("match index") do |o|
+                  j.times do |k|
+                    o.puts("#{k} => return &self.#{k},")
+                  end
+                  o.puts("_ => panic!(\"column index {} out of range for #{name}\", index)")
+                end
+              end
+            end
+
+            o.block("impl std::ops::IndexMut<usize> for #{name}", pad: true) do |o|
+              o.puts("#[inline]")
+              o.block("fn index_mut(&mut self, index: usize) -> &mut #{vector_name}") do |o|
+                o.block("match index") do |o|
+                  j.times do |k|
+                    o.puts("#{k} => return &mut self.#{k},")
+                  end
+                  o.puts("_ => panic!(\"column index {} out of range for #{name}\", index)")
+                end
+              end
+            end
+
+            o.block("impl std::ops::Index<(usize, usize)> for #{name}", pad: true) do |o|
+              o.puts("type Output = #{scalar};")
+              o.puts
+              o.puts("#[inline(always)]", pad: true)
+              o.block("fn index(&self, index: (usize, usize)) -> &#{scalar}") do |o|
+                o.puts("return &self[index.1][index.0];")
+              end
+            end
+
+            o.block("impl std::ops::IndexMut<(usize, usize)> for #{name}", pad: true) do |o|
+              o.puts("#[inline(always)]")
+              o.block("fn index_mut(&mut self, index: (usize, usize)) -> &mut #{scalar}") do |o|
+                o.puts("return &mut self[index.1][index.0];")
+              end
+            end
+
+            o.block("impl From<[[#{scalar}; #{i}]; #{j}]> for #{name}", pad: true) do |o|
+              o.puts("#[inline(always)]")
+              o.block("fn from(x: [[#{scalar}; #{i}]; #{j}]) -> #{name}") do |o|
+                o.puts("return #{name}(#{j.times.map { |k| "#{vector_name}::from(x[#{k}])" }.join(", ")});")
+              end
+            end
+
+            o.block("impl From<#{name}> for [[#{scalar}; #{i}]; #{j}]", pad: true) do |o|
+              o.puts("#[inline(always)]")
+              o.block("fn from(x: #{name}) -> [[#{scalar}; #{i}]; #{j}]") do |o|
+                o.puts("return [#{j.times.map { |k| "x.#{k}.into()" }.join(", ")}];")
+              end
+            end
+
+            other_type = type == "float" ? "double" : "float"
+
+            o.block("impl From<#{other_type}#{j}x#{i}> for #{name}", pad: true) do |o|
+              o.puts("#[inline(always)]")
+              o.block("fn from(x: #{other_type}#{j}x#{i}) -> #{name}") do |o|
+                o.puts("return x.to_#{type}();")
+              end
+            end
+
+            if i == j
+              o.block("impl Determinant for #{name}", pad: true) do |o|
+                o.puts("type Scalar = #{scalar};")
+                o.puts
+                o.puts("#[inline(always)]")
+                o.block("fn determinant(self) -> #{scalar}") do |o|
+                  case i
+                  when 2
+                    o.puts("return (self.0).0 * (self.1).1 - (self.0).1 * (self.1).0;")
+                  when 3
+                    o.puts("return dot(self.0, cross(self.1, self.2));")
+                  when 4
+                    ["a", "b", "c", "d"].each_with_index do |v, k|
+                      o.puts("let #{v} = #{type}3((self.#{k}).0, (self.#{k}).1, (self.#{k}).2);")
+                    end
+                    o.puts
+                    o.puts("let u = a * (self.1).3 - b * (self.0).3;")
+                    o.puts("let v = c * (self.3).3 - d * (self.2).3;")
+                    o.puts
+                    o.puts("return dot(cross(a, b), v) + dot(cross(c, d), u);")
+                  end
+                end
+              end
+            end
+
+            o.block("impl Matrix for #{name}", pad: true) do |o|
+              o.puts("type Scalar = #{scalar};")
+              o.puts("type Column = #{vector_name};")
+              o.puts("type Row = #{transpose_vector_name};")
+              o.puts("type Transpose = #{transpose_name};")
+              o.puts("type FloatMatrix = float#{j}x#{i};")
+              o.puts("type DoubleMatrix = double#{j}x#{i};")
+              o.puts
+              o.puts("const ROWS: usize = #{i};")
+              o.puts("const COLUMNS: usize = #{j};")
+              o.puts
+              o.puts("#[inline(always)]", pad: true)
+              o.block("fn column(self, index: usize) -> #{vector_name}") do |o|
+                o.puts("return self[index];")
+              end
+
+              o.puts("#[inline(always)]", pad: true)
+              o.block("fn row(self, index: usize) -> #{transpose_vector_name}") do |o|
+                o.puts("return #{transpose_vector_name}(#{j.times.map { |k| "self.#{k}[index]" }.join(", ")});")
+              end
+
+              o.puts("#[inline(always)]", pad: true)
+              o.block("fn map(self, f: &Fn(#{scalar}) -> #{scalar}) -> #{name}") do |o|
+                o.puts("return #{name}(#{j.times.map { |k| "self.#{k}.map_unary(f)" }.join(", ")});")
+              end
+
+              o.puts("#[inline(always)]", pad: true)
+              o.block("fn transpose(self) -> #{transpose_name}") do |o|
+                o.puts("return #{name}::transpose(self);")
+              end
+
+              ["float", "double"].each do |t|
+                o.puts("#[inline(always)]", pad: true)
+                o.block("fn to_#{t}(self) -> #{t}#{j}x#{i}") do |o|
+                  o.puts("return #{t}#{j}x#{i}(#{j.times.map { |k| "self.#{k}.to_#{t}()" }.join(", ")});")
+                end
+              end
+            end
+
+            if i == j
+              o.block("impl SquareMatrix for #{name}", pad: true) do |o|
+                o.puts("#[inline(always)]", pad: true)
+                o.block("fn identity() -> #{name}") do |o|
+                  o.puts("return #{name}::identity();")
+                end
+
+                o.puts("#[inline(always)]", pad: true)
+                o.block("fn inverse(self) -> #{name}") do |o|
+                  o.puts("return #{name}::inverse(self);")
+                end
+
+                o.puts("#[inline(always)]", pad: true)
+                o.block("fn trace(self) -> #{scalar}") do |o|
+                  o.puts("return #{name}::trace(self);")
+                end
+              end
+            end
+
             o.block("impl #{name}", pad: true) do
               o.puts("#[inline(always)]", pad: true)
               o.block("pub fn from_columns(#{j.times.map { |k| "c#{k}: #{vector_name}" }.join(", ")}) -> #{name}") do |o|
@@ -144,6 +395,18 @@ module Bridge
                 o.puts("return #{transpose_name}(#{i.times.map { |k| "r#{k}" }.join(", ")}).transpose();")
               end
 
+              o.puts("#[inline(always)]", pad: true)
+              o.block("pub fn from_row_major(x: [[#{scalar}; #{j}]; #{i}]) -> #{name}") do |o|
+                o.puts("return #{name}::from_rows(#{i.times.map { |k| "#{transpose_vector_name}::from(x[#{k}])" }.join(", ")});")
+              end
+
+              o.puts("#[inline(always)]", pad: true)
+              o.block("pub fn to_row_major(self) -> [[#{scalar}; #{j}]; #{i}]") do |o|
+                o.puts("let t = self.transpose();")
+                o.puts
+                o.puts("return [#{i.times.map { |k| "t.#{k}.into()" }.join(", ")}];")
+              end
+
               if i == j
                 o.puts("#[inline(always)]", pad: true)
                 o.block("pub fn identity() -> #{name}") do |o|
@@ -151,6 +414,13 @@ module Bridge
 
                   o.puts("return #{name}(#{identity});")
                 end
+
+                o.puts("#[inline(always)]", pad: true)
+                o.block("pub fn from_diagonal(d: #{vector_name}) -> #{name}") do |o|
+                  diagonal = j.times.map { |k| "#{vector_name}(#{(["0.0"] * i).tap { |ary| ary[k] = "d.#{k}" }.join(", ")})" }.join(", ")
+
+                  o.puts("return #{name}(#{diagonal});")
+                end
               end
 
               if i == 4 && j == 4
@@ -162,6 +432,14 @@ module Bridge
                   o.puts("return #{name}(#{x});")
                 end
 
+                o.puts("#[inline(always)]", pad: true)
+                o.block("pub fn from_scale_vector(scale: #{type}3) -> #{name}") do |o|
+                  x = (j - 1).times.map { |k| "#{vector_name}(#{(["0.0"] * i).tap { |ary| ary[k] = "scale.#{k}" }.join(", ")})" }.join(", ")
+                  x += ", #{vector_name}(0.0, 0.0, 0.0, 1.0)"
+
+                  o.puts("return #{name}(#{x});")
+                end
+
                 o.puts("#[inline(always)]", pad: true)
                 o.block("pub fn from_translation(x: #{scalar}, y: #{scalar}, z: #{scalar}) -> #{name}") do |o|
                   x = (j - 1).times.map { |k| "#{vector_name}(#{(["0.0"] * i).tap { |ary| ary[k] = "1.0" }.join(", ")})" }.join(", ")
@@ -172,9 +450,9 @@ module Bridge
 
                 o.puts("#[inline(always)]", pad: true)
                 o.block("pub fn from_euler_angles(roll: #{scalar}, pitch: #{scalar}, yaw: #{scalar}) -> #{name}") do |o|
-                  o.puts("let (sr, cr) = roll.sin_cos();", pad: true)
-                  o.puts("let (sp, cp) = pitch.sin_cos();")
-                  o.puts("let (sy, cy) = yaw.sin_cos();")
+                  o.puts("let (sr, cr) = scalar::FloatScalar::sin_cos(roll);", pad: true)
+                  o.puts("let (sp, cp) = scalar::FloatScalar::sin_cos(pitch);")
+                  o.puts("let (sy, cy) = scalar::FloatScalar::sin_cos(yaw);")
 
                   o.puts("return #{name}(", pad: true)
                   o.puts("  #{vector_name}(cy * cp, sy * cp, -sp, 0.0),")
@@ -213,7 +491,7 @@ module Bridge
 
                 o.puts("#[inline(always)]", pad: true)
                 o.block("pub fn perspective_fov(fov_y: #{scalar}, aspect: #{scalar}, near: #{scalar}, far: #{scalar}) -> #{name}") do |o|
-                  o.puts("let y_scale = 1.0 / (0.5 * fov_y).tan();", pad: true)
+                  o.puts("let y_scale = 1.0 / scalar::FloatScalar::tan(0.5 * fov_y);", pad: true)
                   o.puts("let x_scale = y_scale / aspect;")
                   o.puts("let z_scale = far / (far - near);")
 
@@ -249,6 +527,11 @@ module Bridge
                 o.puts("return #{name}(#{j.times.map { |k| "a * x.#{k} + b * y.#{k}" }.join(", ")});")
               end
 
+              o.puts("#[inline(always)]", pad: true)
+              o.block("pub fn hadamard(self, other: #{name}) -> #{name}") do |o|
+                o.puts("return #{name}(#{j.times.map { |k| "self.#{k} * other.#{k}" }.join(", ")});")
+              end
+
               o.puts("#[inline(always)]", pad: true)
               o.block("pub fn transpose(self) -> #{transpose_name}") do |o|
                 i.times do |k|
@@ -258,15 +541,224 @@ module Bridge
                 o.puts("return #{transpose_name}(#{i.times.map { |k| "c#{k}" }.join(", ")});")
               end
 
+              if i == j
+                o.puts("#[inline(always)]", pad: true)
+                o.block("pub fn diagonal(self) -> #{vector_name}") do |o|
+                  o.puts("return #{vector_name}(#{j.times.map { |k| "(self.#{k}).#{k}" }.join(", ")});")
+                end
+
+                o.puts("#[inline(always)]", pad: true)
+                o.block("pub fn trace(self) -> #{scalar}") do |o|
+                  o.puts("return #{j.times.map { |k| "(self.#{k}).#{k}" }.join(" + ")};")
+                end
+              end
+
+              (2 .. [i, j].min).each do |k|
+                next if k == i && k == j
+
+                o.puts("#[inline(always)]", pad: true)
+                o.block("pub fn upper_left_#{k}x#{k}(self) -> #{type}#{k}x#{k}") do |o|
+                  if k == i
+                    o.puts("return #{type}#{k}x#{k}(#{k.times.map { |c| "self.#{c}" }.join(", ")});")
+                  else
+                    o.puts("return #{type}#{k}x#{k}(#{k.times.map { |c| "#{type}#{k}(#{k.times.map { |r| "(self.#{c}).#{r}" }.join(", ")})" }.join(", ")});")
+                  end
+                end
+              end
+
+              if (i == j && i < max_matrix_size) || j == i + 1
+                homogeneous_vector_name = "#{type}#{i + 1}"
+                columns = j.times.map { |c| "#{homogeneous_vector_name}(#{(i.times.map { |r| "(self.#{c}).#{r}" } + [c == i ? "1.0" : "0.0"]).join(", ")})" }
+                columns << "#{homogeneous_vector_name}(#{(["0.0"] * i + ["1.0"]).join(", ")})" if i == j
+
+                o.puts("#[inline(always)]", pad: true)
+                o.block("pub fn to_homogeneous(self) -> #{homogeneous_vector_name}x#{i + 1}") do |o|
+                  o.puts("return #{homogeneous_vector_name}x#{i + 1}(#{columns.join(", ")});")
+                end
+              end
+
+              if i == j && i >= 3
+                o.puts("#[inline(always)]", pad: true)
+                o.block("pub fn to_affine(self) -> #{type}#{j}x#{i - 1}") do |o|
+                  o.puts("return #{type}#{j}x#{i - 1}(#{j.times.map { |c| "#{type}#{i - 1}(#{(i - 1).times.map { |r| "(self.#{c}).#{r}" }.join(", ")})" }.join(", ")});")
+                end
+
+                if i == max_matrix_size
+                  o.puts("#[inline(always)]", pad: true)
+                  o.block("pub fn to_affine_rows(self) -> #{type}#{j - 1}x#{i}") do |o|
+                    o.puts("return #{type}#{j - 1}x#{i}(#{(j - 1).times.map { |r| "#{type}#{i}(#{j.times.map { |c| "(self.#{c}).#{r}" }.join(", ")})" }.join(", ")});")
+                  end
+
+                  o.puts("#[inline(always)]", pad: true)
+                  o.block("pub fn from_affine_rows(m: #{type}#{j - 1}x#{i}) -> #{name}") do |o|
+                    o.puts("return #{name}(#{j.times.map { |c| "#{type}#{i}(#{((j - 1).times.map { |r| "(m.#{r}).#{c}" } + [c == j - 1 ? "1.0" : "0.0"]).join(", ")})" }.join(", ")});")
+                  end
+                end
+
+                o.puts("#[inline]", pad: true)
+                o.block("pub fn minor(self, row: usize, column: usize) -> #{type}#{i - 1}x#{i - 1}") do |o|
+                  o.puts("assert!(row < #{i} && column < #{i}, \"minor ({}, {}) out of range for #{name}\", row, column);")
+                  o.puts
+                  o.puts("let mut m = #{type}#{i - 1}x#{i - 1}::default();")
+                  o.puts
+                  o.block("for c in 0 .. #{i - 1}") do |o|
+                    o.block("for r in 0 .. #{i - 1}") do |o|
+                      o.puts("m[(r, c)] = self[(r + (r >= row) as usize, c + (c >= column) as usize)];")
+                    end
+                  end
+                  o.puts
+                  o.puts("return m;")
+                end
+              end
+
               # TODO: matrix_determinant
 
               if i == j && ["f32", "f64"].include?(scalar)
                 typecode = { "f32" => "f", "f64" => "d" }.fetch(scalar)
 
-                o.puts("#[inline(always)]", pad: true)
+                o.puts("#[cfg(not(feature = \"portable\"))]", pad: true)
+                o.puts("#[inline(always)]")
                 o.block("pub fn inverse(self) -> #{name}") do |o|
                   o.puts("return unsafe { __invert_#{typecode}#{i}(self) };")
                 end
+
+                vector3_name = "#{type}3"
+
+                o.puts("#[cfg(feature = \"portable\")]", pad: true)
+                o.puts("#[inline(always)]")
+                o.block("pub fn inverse(self) -> #{name}") do |o|
+                  o.puts("return self.inverse_with_det().0;")
+                end
+
+                o.puts("#[cfg(not(feature = \"portable\"))]", pad: true)
+                o.puts("#[inline(always)]")
+                o.block("pub fn inverse_with_det(self) -> (#{name}, #{scalar})") do |o|
+                  o.puts("return (self.inverse(), self.determinant());")
+                end
+
+                o.puts("#[cfg(feature = \"portable\")]", pad: true)
+                o.puts("#[inline(always)]")
+                o.block("pub fn inverse_with_det(self) -> (#{name}, #{scalar})") do |o|
+                  case i
+                  when 2
+                    o.puts("let det = (self.0).0 * (self.1).1 - (self.0).1 * (self.1).0;")
+                    o.puts
+                    o.puts("return (#{name}(#{vector_name}((self.1).1, -(self.0).1), #{vector_name}(-(self.1).0, (self.0).0)) * (1.0 / det), det);")
+                  when 3
+                    o.puts("let r0 = cross(self.1, self.2);")
+                    o.puts("let r1 = cross(self.2, self.0);")
+                    o.puts("let r2 = cross(self.0, self.1);")
+                    o.puts
+                    o.puts("let det = dot(self.0, r0);")
+                    o.puts
+                    o.puts("return (#{name}::from_rows(r0, r1, r2) * (1.0 / det), det);")
+                  when 4
+                    ["a", "b", "c", "d"].each_with_index do |v, k|
+                      o.puts("let #{v} = #{vector3_name}((self.#{k}).0, (self.#{k}).1, (self.#{k}).2);")
+                    end
+                    o.puts
+                    ["x", "y", "z", "w"].each_with_index do |v, k|
+                      o.puts("let #{v} = (self.#{k}).3;")
+                    end
+                    o.puts
+                    o.puts("let s = cross(a, b);")
+                    o.puts("let t = cross(c, d);")
+                    o.puts("let u = a * y - b * x;")
+                    o.puts("let v = c * w - d * z;")
+                    o.puts
+                    o.puts("let det = dot(s, v) + dot(t, u);")
+                    o.puts
+                    o.puts("let r0 = cross(b, v) + t * y;")
+                    o.puts("let r1 = cross(v, a) - t * x;")
+                    o.puts("let r2 = cross(d, u) + s * w;")
+                    o.puts("let r3 = cross(u, c) - s * z;")
+                    o.puts
+                    rows = [["r0", "-dot(b, t)"], ["r1", "dot(a, t)"], ["r2", "-dot(d, s)"], ["r3", "dot(c, s)"]]
+                    o.puts("return (#{name}::from_rows(#{rows.map { |r, e| "#{vector_name}(#{r}.0, #{r}.1, #{r}.2, #{e})" }.join(", ")}) * (1.0 / det), det);")
+                  end
+                end
+
+                o.puts("#[inline(always)]", pad: true)
+                o.block("pub fn try_inverse(self) -> Option<#{name}>") do |o|
+                  o.puts("return self.try_inverse_epsilon(0.0);")
+                end
+
+                o.puts("#[inline]", pad: true)
+                o.block("pub fn try_inverse_epsilon(self, epsilon: #{scalar}) -> Option<#{name}>") do |o|
+                  o.puts("let (inverse, det) = self.inverse_with_det();")
+                  o.puts
+                  o.puts("if (det > epsilon || det < -epsilon) && (#{j.times.map { |k| "inverse.#{k}.is_finite()" }.join(" & ")}).all() {")
+                  o.puts("  return Some(inverse);")
+                  o.puts("}")
+                  o.puts
+                  o.puts("return None;")
+                end
+
+                o.puts("#[inline]", pad: true)
+                o.block("pub fn condition_number_estimate(self) -> #{scalar}") do |o|
+                  o.puts("match self.try_inverse() {")
+                  o.puts("  Some(inverse) => {")
+                  o.puts("    let a = #{j.times.map { |k| "self.#{k}.abs()" }.join(" + ")};")
+                  o.puts("    let b = #{j.times.map { |k| "inverse.#{k}.abs()" }.join(" + ")};")
+                  o.puts
+                  o.puts("    return a.reduce_max() * b.reduce_max();")
+                  o.puts("  },")
+                  o.puts("  None => return std::#{scalar}::INFINITY")
+                  o.puts("}")
+                end
+              end
+
+              if j == 4 && i >= 3
+                matrix3_name = "#{type}3x3"
+
+                if i == 4
+                  upper = 3.times.map { |k| "#{type}3((self.#{k}).0, (self.#{k}).1, (self.#{k}).2)" }.join(", ")
+                  translation = "#{type}3((self.3).0, (self.3).1, (self.3).2)"
+                else
+                  upper = "self.0, self.1, self.2"
+                  translation = "self.3"
+                end
+
+                [["inverse_affine", "inverse()"], ["inverse_rigid", "transpose()"]].each do |fn, op|
+                  o.puts("#[inline(always)]", pad: true)
+                  o.block("pub fn #{fn}(self) -> #{name}") do |o|
+                    o.puts("let m = #{matrix3_name}(#{upper}).#{op};")
+                    o.puts("let t = -(m * #{translation});")
+                    o.puts
+                    if i == 4
+                      o.puts("return #{name}(#{3.times.map { |k| "#{vector_name}((m.#{k}).0, (m.#{k}).1, (m.#{k}).2, 0.0)" }.join(", ")}, #{vector_name}(t.0, t.1, t.2, 1.0));")
+                    else
+                      o.puts("return #{name}(m.0, m.1, m.2, t);")
+                    end
+                  end
+                end
+
+                o.puts("#[inline(always)]", pad: true)
+                o.block("pub fn normal_matrix(self) -> #{matrix3_name}") do |o|
+                  o.puts("return #{matrix3_name}(#{upper}).inverse().transpose();")
+                end
+              end
+
+              # The transposed affine layout, every column holds a row of the 3x4 transform
+              if j == 3 && i == 4
+                matrix3_name = "#{type}3x3"
+                rows = 3.times.map { |k| "#{type}3((self.#{k}).0, (self.#{k}).1, (self.#{k}).2)" }.join(", ")
+                translation = "#{type}3((self.0).3, (self.1).3, (self.2).3)"
+
+                [["inverse_affine", "inverse()"], ["inverse_rigid", "transpose()"]].each do |fn, op|
+                  o.puts("#[inline(always)]", pad: true)
+                  o.block("pub fn #{fn}(self) -> #{name}") do |o|
+                    o.puts("let m = #{matrix3_name}(#{rows}).#{op};")
+                    o.puts("let t = -(m.transpose() * #{translation});")
+                    o.puts
+                    o.puts("return #{name}(#{3.times.map { |k| "#{vector_name}((m.#{k}).0, (m.#{k}).1, (m.#{k}).2, t.#{k})" }.join(", ")});")
+                  end
+                end
+
+                o.puts("#[inline(always)]", pad: true)
+                o.block("pub fn normal_matrix(self) -> #{matrix3_name}") do |o|
+                  o.puts("return #{matrix3_name}(#{rows}).inverse();")
+                end
               end
 
               # matrix_multiply is expressed via the `Dot` trait
@@ -0,0 +1,198 @@
+use ::*;
+use scalar::Lane;
+
+/// Element-wise comparison with a tolerance for float vectors and matrices. The `approx_eq_*` methods return a mask
+/// with one lane per element, vectors use their `Boolean` type and matrices an array with the mask of each column,
+/// and the `almost_equal_elements*` methods are true if every element matches.
+///
+/// Equal elements always match, including infinities, and NaN never matches anything.
+pub trait ApproxEq : Sized {
+  type Epsilon;
+  type Mask;
+
+  /// `|x - y| <= epsilon`
+  fn approx_eq_abs(self, other: Self, epsilon: Self::Epsilon) -> Self::Mask;
+
+  /// `|x - y| <= epsilon * max(|x|, |y|)`, an infinity only matches the same infinity.
+  fn approx_eq_rel(self, other: Self, epsilon: Self::Epsilon) -> Self::Mask;
+
+  /// At most `ulps` representable numbers apart, numbers of opposite signs only match if they are both zero
+  /// and an infinity only matches the same infinity.
+  fn approx_eq_ulps(self, other: Self, ulps: u32) -> Self::Mask;
+
+  fn almost_equal_elements(self, other: Self, epsilon: Self::Epsilon) -> bool;
+  fn almost_equal_elements_relative(self, other: Self, epsilon: Self::Epsilon) -> bool;
+  fn almost_equal_elements_ulps(self, other: Self, ulps: u32) -> bool;
+}
+
+impl<T: Float> ApproxEq for T {
+  type Epsilon = T::Scalar;
+  type Mask = T::Boolean;
+
+  #[inline(always)]
+  fn approx_eq_abs(self, other: Self, epsilon: T::Scalar) -> T::Boolean {
+    return self.eq(other) | (self - other).abs().le(T::broadcast(epsilon));
+  }
+
+  #[inline(always)]
+  fn approx_eq_rel(self, other: Self, epsilon: T::Scalar) -> T::Boolean {
+    let scale = self.abs().max(other.abs());
+
+    let finite = self.is_finite() & other.is_finite();
+
+    return self.eq(other) | (finite & (self - other).abs().le(T::broadcast(epsilon) * scale));
+  }
+
+  #[inline(always)]
+  fn approx_eq_ulps(self, other: Self, ulps: u32) -> T::Boolean {
+    let (x, y) = (self.to_boolean(), other.to_boolean());
+    let magnitude = T::Boolean::broadcast(T::SIGN_MASK);
+
+    // With the same sign the distance of the magnitude bits is the number of representable numbers in between
+    let same_sign = T::Boolean::bitcast((x ^ y).eq((x ^ y) & magnitude));
+    let distance = ((x & magnitude) - (y & magnitude)).abs();
+    // Clamped to the largest distance a lane can hold so that a huge `ulps` doesn't wrap around to negative
+    let max = T::SIGN_MASK.cast::<i64>();
+    let limit = Lane::from_i64(if (ulps as i64) < max { ulps as i64 } else { max });
+    let close = T::Boolean::bitcast(distance.le(T::Boolean::broadcast(limit)));
+
+    let finite = self.is_finite() & other.is_finite();
+
+    return self.eq(other) | (finite & same_sign & close);
+  }
+
+  #[inline(always)]
+  fn almost_equal_elements(self, other: Self, epsilon: T::Scalar) -> bool {
+    return self.approx_eq_abs(other, epsilon).all();
+  }
+
+  #[inline(always)]
+  fn almost_equal_elements_relative(self, other: Self, epsilon: T::Scalar) -> bool {
+    return self.approx_eq_rel(other, epsilon).all();
+  }
+
+  #[inline(always)]
+  fn almost_equal_elements_ulps(self, other: Self, ulps: u32) -> bool {
+    return self.approx_eq_ulps(other, ulps).all();
+  }
+}
+
+#[inline(always)]
+pub fn almost_equal_elements<T: ApproxEq>(x: T, y: T, epsilon: T::Epsilon) -> bool {
+  return x.almost_equal_elements(y, epsilon);
+}
+
+#[inline(always)]
+pub fn almost_equal_elements_relative<T: ApproxEq>(x: T, y: T, epsilon: T::Epsilon) -> bool {
+  return x.almost_equal_elements_relative(y, epsilon);
+}
+
+#[inline(always)]
+pub fn almost_equal_elements_ulps<T: ApproxEq>(x: T, y: T, ulps: u32) -> bool {
+  return x.almost_equal_elements_ulps(y, ulps);
+}
+
+macro_rules! impl_approx_matrix {
+  ($name:ident, $column:ident, $scalar:ty, ($($c:tt),+)) => {
+    impl ApproxEq for $name {
+      type Epsilon = $scalar;
+      type Mask = [<$column as Vector>::Boolean; $(impl_approx_matrix!(@one $c) +)+ 0];
+
+      #[inline(always)]
+      fn approx_eq_abs(self, other: Self, epsilon: $scalar) -> Self::Mask {
+        return [$(self.$c.approx_eq_abs(other.$c, epsilon)),+];
+      }
+
+      #[inline(always)]
+      fn approx_eq_rel(self, other: Self, epsilon: $scalar) -> Self::Mask {
+        return [$(self.$c.approx_eq_rel(other.$c, epsilon)),+];
+      }
+
+      #[inline(always)]
+      fn approx_eq_ulps(self, other: Self, ulps: u32) -> Self::Mask {
+        return [$(self.$c.approx_eq_ulps(other.$c, ulps)),+];
+      }
+
+      #[inline(always)]
+      fn almost_equal_elements(self, other: Self, epsilon: $scalar) -> bool {
+        return $(self.$c.almost_equal_elements(other.$c, epsilon))&&+;
+      }
+
+      #[inline(always)]
+      fn almost_equal_elements_relative(self, other: Self, epsilon: $scalar) -> bool {
+        return $(self.$c.almost_equal_elements_relative(other.$c, epsilon))&&+;
+      }
+
+      #[inline(always)]
+      fn almost_equal_elements_ulps(self, other: Self, ulps: u32) -> bool {
+        return $(self.$c.almost_equal_elements_ulps(other.$c, ulps))&&+;
+      }
+    }
+  };
+
+  (@one $c:tt) => { 1 };
+}
+
+impl_approx_matrix!(float2x2, float2, f32, (0, 1));
+impl_approx_matrix!(float2x3, float3, f32, (0, 1));
+impl_approx_matrix!(float2x4, float4, f32, (0, 1));
+impl_approx_matrix!(float3x2, float2, f32, (0, 1, 2));
+impl_approx_matrix!(float3x3, float3, f32, (0, 1, 2));
+impl_approx_matrix!(float3x4, float4, f32, (0, 1, 2));
+impl_approx_matrix!(float4x2, float2, f32, (0, 1, 2, 3));
+impl_approx_matrix!(float4x3, float3, f32, (0, 1, 2, 3));
+impl_approx_matrix!(float4x4, float4, f32, (0, 1, 2, 3));
+
+impl_approx_matrix!(double2x2, double2, f64, (0, 1));
+impl_approx_matrix!(double2x3, double3, f64, (0, 1));
+impl_approx_matrix!(double2x4, double4, f64, (0, 1));
+impl_approx_matrix!(double3x2, double2, f64, (0, 1, 2));
+impl_approx_matrix!(double3x3, double3, f64, (0, 1, 2));
+impl_approx_matrix!(double3x4, double4, f64, (0, 1, 2));
+impl_approx_matrix!(double4x2, double2, f64, (0, 1, 2, 3));
+impl_approx_matrix!(double4x3, double3, f64, (0, 1, 2, 3));
+impl_approx_matrix!(double4x4, double4, f64, (0, 1, 2, 3));
+
+/// Asserts that every element of two vectors or matrices is within `epsilon` of the other one.
+#[macro_export]
+macro_rules! assert_approx_eq {
+  ($left:expr, $right:expr, $epsilon:expr) => ({
+    match (&$left, &$right, &$epsilon) {
+      (left, right, epsilon) => {
+        if !$crate::ApproxEq::almost_equal_elements(*left, *right, *epsilon) {
+          panic!("assertion failed: `(left ≈ right)` (epsilon: `{:?}`)\n  left: `{:?}`,\n right: `{:?}`", epsilon, left, right);
+        }
+      }
+    }
+  });
+}
+
+/// Asserts that every element of two vectors or matrices is within `epsilon` times the larger magnitude of the other
+/// one.
+#[macro_export]
+macro_rules! assert_approx_eq_relative {
+  ($left:expr, $right:expr, $epsilon:expr) => ({
+    match (&$left, &$right, &$epsilon) {
+      (left, right, epsilon) => {
+        if !$crate::ApproxEq::almost_equal_elements_relative(*left, *right, *epsilon) {
+          panic!("assertion failed: `(left ≈ right)` (relative epsilon: `{:?}`)\n  left: `{:?}`,\n right: `{:?}`", epsilon, left, right);
+        }
+      }
+    }
+  });
+}
+
+/// Asserts that every element of two vectors or matrices is at most `ulps` representable numbers away from the other
+/// one.
+#[macro_export]
+macro_rules! assert_approx_eq_ulps {
+  ($left:expr, $right:expr, $ulps:expr) => ({
+    match (&$left, &$right, &$ulps) {
+      (left, right, ulps) => {
+        if !$crate::ApproxEq::almost_equal_elements_ulps(*left, *right, *ulps) {
+          panic!("assertion failed: `(left ≈ right)` (ulps: `{:?}`)\n  left: `{:?}`,\n right: `{:?}`", ulps, left, right);
+        }
+      }
+    }
+  });
+}
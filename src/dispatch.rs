@@ -0,0 +1,135 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub const SSE41: usize = 0x01;
+pub const AVX: usize = 0x02;
+pub const AVX2: usize = 0x04;
+pub const FMA: usize = 0x08;
+pub const AVX512F: usize = 0x10;
+pub const SSE: usize = 0x20;
+pub const SSE2: usize = 0x40;
+
+pub const ALL: usize = SSE | SSE2 | SSE41 | AVX | AVX2 | FMA | AVX512F;
+
+// Features the compiler is already allowed to use everywhere, these can not be turned off at runtime
+const STATIC: usize =
+  (cfg!(target_feature = "sse") as usize) * SSE |
+  (cfg!(target_feature = "sse2") as usize) * SSE2 |
+  (cfg!(target_feature = "sse4.1") as usize) * SSE41 |
+  (cfg!(target_feature = "avx") as usize) * AVX |
+  (cfg!(target_feature = "avx2") as usize) * AVX2 |
+  (cfg!(target_feature = "fma") as usize) * FMA |
+  (cfg!(target_feature = "avx512f") as usize) * AVX512F;
+
+const INITIALIZED: usize = 0x80;
+
+static DETECTED: AtomicUsize = AtomicUsize::new(0);
+static DISABLED: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns true if every feature in `features` may be used by the kernels.
+#[inline(always)]
+pub fn has(features: usize) -> bool {
+  return STATIC & features == features || self::features() & features == features;
+}
+
+/// Returns the set of features the kernels currently dispatch on, that is the
+/// detected features minus anything turned off with `restrict`.
+#[inline]
+pub fn features() -> usize {
+  return (detected() & !DISABLED.load(Ordering::Relaxed)) | STATIC;
+}
+
+/// Returns the set of features supported by the processor we are running on,
+/// the detection is only done once.
+#[inline]
+pub fn detected() -> usize {
+  let mut features = DETECTED.load(Ordering::Relaxed);
+
+  if features == 0 {
+    features = detect() | INITIALIZED;
+
+    DETECTED.store(features, Ordering::Relaxed);
+  }
+
+  return (features | STATIC) & ALL;
+}
+
+/// Limit the kernels to the given set of features, so that every path can be
+/// exercised on the same machine. Features enabled at compile time are always
+/// used.
+pub fn restrict(features: usize) {
+  DISABLED.store(ALL & !features, Ordering::Relaxed);
+}
+
+/// Undo any previous `restrict`.
+pub fn reset() {
+  DISABLED.store(0, Ordering::Relaxed);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+fn detect() -> usize {
+  let (max_leaf, _, _, _) = unsafe { cpuid(0, 0) };
+  let (_, _, ecx1, edx1) = unsafe { cpuid(1, 0) };
+  let (_, ebx7, _, _) = if max_leaf >= 7 { unsafe { cpuid(7, 0) } } else { (0, 0, 0, 0) };
+
+  // The OS has to save the wider registers on context switches for us to be able to use them
+  let xcr0 = if ecx1 & (1 << 27) != 0 { unsafe { xgetbv(0) } } else { 0 };
+  let ymm = xcr0 & 0x06 == 0x06;
+  let zmm = xcr0 & 0xE6 == 0xE6;
+
+  let mut features = 0;
+
+  if edx1 & (1 << 25) != 0 {
+    features |= SSE;
+  }
+
+  if edx1 & (1 << 26) != 0 {
+    features |= SSE2;
+  }
+
+  if ecx1 & (1 << 19) != 0 {
+    features |= SSE41;
+  }
+
+  if ymm && ecx1 & (1 << 28) != 0 {
+    features |= AVX;
+  }
+
+  if ymm && ecx1 & (1 << 12) != 0 {
+    features |= FMA;
+  }
+
+  if ymm && ebx7 & (1 << 5) != 0 {
+    features |= AVX2;
+  }
+
+  if zmm && ebx7 & (1 << 16) != 0 {
+    features |= AVX512F;
+  }
+
+  return features;
+}
+
+#[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+fn detect() -> usize {
+  return 0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[inline]
+unsafe fn cpuid(leaf: u32, subleaf: u32) -> (u32, u32, u32, u32) {
+  let (a, b, c, d): (u32, u32, u32, u32);
+
+  asm!("cpuid" : "={eax}"(a), "={ebx}"(b), "={ecx}"(c), "={edx}"(d) : "{eax}"(leaf), "{ecx}"(subleaf) : : "volatile");
+
+  return (a, b, c, d);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[inline]
+unsafe fn xgetbv(index: u32) -> u64 {
+  let (a, d): (u32, u32);
+
+  asm!("xgetbv" : "={eax}"(a), "={edx}"(d) : "{ecx}"(index) : : "volatile");
+
+  return ((d as u64) << 32) | (a as u64);
+}
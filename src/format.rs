@@ -0,0 +1,379 @@
+use std;
+use std::fmt;
+use std::str::FromStr;
+use ::*;
+
+// Vectors are written as `(1, 2, 3, 4)` and matrices as one `[1, 2, 3]` line per row with the columns lined up, any
+// formatting flags (precision, width, sign) are applied to every element. Parsing takes the same format back as well
+// as the literal form that `Debug` prints, e.g. `float4(1, 2, 3, 4)` or `float2x2(float2(1, 0), float2(0, 1))`.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+  /// Expected the given token at the byte offset
+  Expected(&'static str, usize),
+  /// The element at the byte offset isn't a valid number for the scalar type
+  InvalidNumber(usize),
+  /// The number of lanes or rows at the byte offset doesn't match the type
+  WrongLength { expected: usize, found: usize, position: usize }
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    return match *self {
+      ParseError::Expected(token, position) => write!(f, "expected `{}` at offset {}", token, position),
+      ParseError::InvalidNumber(position) => write!(f, "invalid number at offset {}", position),
+      ParseError::WrongLength { expected, found, position } => write!(f, "expected {} elements but found {} at offset {}", expected, found, position)
+    };
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+  fn description(&self) -> &str {
+    return "invalid vector or matrix literal";
+  }
+}
+
+struct Parser<'a> {
+  s: &'a str,
+  position: usize
+}
+
+impl<'a> Parser<'a> {
+  fn new(s: &'a str) -> Parser<'a> {
+    return Parser { s: s, position: 0 };
+  }
+
+  fn rest(&self) -> &'a str {
+    return &self.s[self.position ..];
+  }
+
+  fn skip_whitespace(&mut self) {
+    let rest = self.rest();
+
+    self.position += rest.len() - rest.trim_start().len();
+  }
+
+  fn peek(&mut self) -> Option<char> {
+    self.skip_whitespace();
+
+    return self.rest().chars().next();
+  }
+
+  fn eat(&mut self, c: char) -> bool {
+    if self.peek() == Some(c) {
+      self.position += c.len_utf8();
+
+      return true;
+    }
+
+    return false;
+  }
+
+  fn expect(&mut self, c: char, token: &'static str) -> Result<(), ParseError> {
+    return if self.eat(c) { Ok(()) } else { Err(ParseError::Expected(token, self.position)) };
+  }
+
+  fn word(&mut self) -> (usize, &'a str) {
+    self.skip_whitespace();
+
+    let rest = self.rest();
+    let n = rest.find(|c: char| c.is_whitespace() || c == ',' || c == '(' || c == ')' || c == '[' || c == ']').unwrap_or(rest.len());
+    let start = self.position;
+
+    self.position += n;
+
+    return (start, &rest[.. n]);
+  }
+
+  fn name(&mut self, name: &'static str) -> Result<(), ParseError> {
+    let start = self.position;
+    let (position, word) = self.word();
+
+    if word.is_empty() {
+      self.position = start;
+    } else if word != name {
+      return Err(ParseError::Expected(name, position));
+    }
+
+    return Ok(());
+  }
+
+  fn end(&mut self) -> Result<(), ParseError> {
+    return if self.peek().is_none() { Ok(()) } else { Err(ParseError::Expected("end of input", self.position)) };
+  }
+
+  fn lanes<V: Vector>(&mut self, open: char, close: char) -> Result<V, ParseError> where V::Scalar: FromStr {
+    self.expect(open, if open == '(' { "(" } else { "[" })?;
+
+    let mut x = V::from(0);
+    let mut n = 0;
+
+    while !self.eat(close) {
+      if n > 0 {
+        self.expect(',', ",")?;
+
+        // Allow a trailing comma
+        if self.eat(close) {
+          break;
+        }
+      }
+
+      let (position, word) = self.word();
+      let lane = word.parse().map_err(|_| ParseError::InvalidNumber(position))?;
+
+      if n < V::LANES {
+        x = x.replace(n as u32, lane);
+      }
+
+      n += 1;
+    }
+
+    if n != V::LANES {
+      return Err(ParseError::WrongLength { expected: V::LANES, found: n, position: self.position });
+    }
+
+    return Ok(x);
+  }
+
+  fn vector<V: Vector>(&mut self, name: &'static str) -> Result<V, ParseError> where V::Scalar: FromStr {
+    self.name(name)?;
+
+    return self.lanes('(', ')');
+  }
+
+  fn rows<V: Vector>(&mut self, rows: &mut [V]) -> Result<(), ParseError> where V::Scalar: FromStr {
+    let mut n = 0;
+
+    while self.peek() == Some('[') {
+      let row = self.lanes('[', ']')?;
+
+      if n < rows.len() {
+        rows[n] = row;
+      }
+
+      n += 1;
+    }
+
+    if n != rows.len() {
+      return Err(ParseError::WrongLength { expected: rows.len(), found: n, position: self.position });
+    }
+
+    return Ok(());
+  }
+}
+
+// Counts the characters written so that the columns of a matrix can be lined up without allocating
+struct Counter(usize);
+
+impl fmt::Write for Counter {
+  fn write_str(&mut self, s: &str) -> fmt::Result {
+    self.0 += s.chars().count();
+
+    return Ok(());
+  }
+}
+
+fn write_element<W: fmt::Write, T: fmt::Display>(w: &mut W, x: T, precision: Option<usize>, plus: bool, width: usize) -> fmt::Result {
+  return match (precision, plus) {
+    (Some(p), true) => write!(w, "{:>+w$.p$}", x, w = width, p = p),
+    (Some(p), false) => write!(w, "{:>w$.p$}", x, w = width, p = p),
+    (None, true) => write!(w, "{:>+w$}", x, w = width),
+    (None, false) => write!(w, "{:>w$}", x, w = width)
+  };
+}
+
+fn fmt_vector<V: Vector>(x: V, f: &mut fmt::Formatter) -> fmt::Result where V::Scalar: fmt::Display {
+  f.write_str("(")?;
+
+  for i in 0 .. V::LANES {
+    if i > 0 {
+      f.write_str(", ")?;
+    }
+
+    fmt::Display::fmt(&x.extract(i as u32), f)?;
+  }
+
+  return f.write_str(")");
+}
+
+fn fmt_rows<V: Vector>(rows: &[V], f: &mut fmt::Formatter) -> fmt::Result where V::Scalar: fmt::Display {
+  let (precision, plus) = (f.precision(), f.sign_plus());
+  let mut widths = [f.width().unwrap_or(0); 4];
+
+  for row in rows {
+    for (width, x) in widths.iter_mut().zip(*row) {
+      let mut counter = Counter(0);
+
+      write_element(&mut counter, x, precision, plus, 0)?;
+      *width = std::cmp::max(*width, counter.0);
+    }
+  }
+
+  for (j, row) in rows.iter().enumerate() {
+    f.write_str(if j > 0 { "\n[" } else { "[" })?;
+
+    for (i, (x, &width)) in row.into_iter().zip(widths.iter()).enumerate() {
+      if i > 0 {
+        f.write_str(", ")?;
+      }
+
+      write_element(f, x, precision, plus, width)?;
+    }
+
+    f.write_str("]")?;
+  }
+
+  return Ok(());
+}
+
+macro_rules! impl_format_vector {
+  ($name:ident) => {
+    impl fmt::Display for $name {
+      fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return fmt_vector(*self, f);
+      }
+    }
+
+    impl FromStr for $name {
+      type Err = ParseError;
+
+      fn from_str(s: &str) -> Result<$name, ParseError> {
+        let mut p = Parser::new(s);
+        let x = p.vector(stringify!($name))?;
+
+        p.end()?;
+
+        return Ok(x);
+      }
+    }
+  }
+}
+
+macro_rules! impl_format_matrix {
+  ($name:ident, $column:ident, $row:ident, ($($c:tt),+), ($($r:tt),+)) => {
+    impl fmt::Display for $name {
+      fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let t = self.transpose();
+
+        return fmt_rows(&[$(t.$r),+], f);
+      }
+    }
+
+    impl FromStr for $name {
+      type Err = ParseError;
+
+      fn from_str(s: &str) -> Result<$name, ParseError> {
+        let mut p = Parser::new(s);
+
+        let x = if p.peek() == Some('[') {
+          let mut rows = [$row::from(0); $column::LANES];
+
+          p.rows(&mut rows)?;
+
+          $name::from_rows($(rows[$r]),+)
+        } else {
+          p.name(stringify!($name))?;
+          p.expect('(', "(")?;
+
+          let x = $name($({
+            if $c > 0 {
+              p.expect(',', ",")?;
+            }
+
+            p.vector(stringify!($column))?
+          }),+);
+
+          p.eat(',');
+          p.expect(')', ")")?;
+
+          x
+        };
+
+        p.end()?;
+
+        return Ok(x);
+      }
+    }
+  }
+}
+
+impl_format_vector!(char2);
+impl_format_vector!(char3);
+impl_format_vector!(char4);
+impl_format_vector!(char8);
+impl_format_vector!(char16);
+
+impl_format_vector!(uchar2);
+impl_format_vector!(uchar3);
+impl_format_vector!(uchar4);
+impl_format_vector!(uchar8);
+impl_format_vector!(uchar16);
+
+impl_format_vector!(short2);
+impl_format_vector!(short3);
+impl_format_vector!(short4);
+impl_format_vector!(short8);
+impl_format_vector!(short16);
+
+impl_format_vector!(ushort2);
+impl_format_vector!(ushort3);
+impl_format_vector!(ushort4);
+impl_format_vector!(ushort8);
+impl_format_vector!(ushort16);
+
+impl_format_vector!(int2);
+impl_format_vector!(int3);
+impl_format_vector!(int4);
+impl_format_vector!(int8);
+impl_format_vector!(int16);
+
+impl_format_vector!(uint2);
+impl_format_vector!(uint3);
+impl_format_vector!(uint4);
+impl_format_vector!(uint8);
+impl_format_vector!(uint16);
+
+impl_format_vector!(long2);
+impl_format_vector!(long3);
+impl_format_vector!(long4);
+impl_format_vector!(long8);
+impl_format_vector!(long16);
+
+impl_format_vector!(ulong2);
+impl_format_vector!(ulong3);
+impl_format_vector!(ulong4);
+impl_format_vector!(ulong8);
+impl_format_vector!(ulong16);
+
+impl_format_vector!(float2);
+impl_format_vector!(float3);
+impl_format_vector!(float4);
+impl_format_vector!(float8);
+impl_format_vector!(float16);
+
+impl_format_vector!(double2);
+impl_format_vector!(double3);
+impl_format_vector!(double4);
+impl_format_vector!(double8);
+impl_format_vector!(double16);
+
+impl_format_matrix!(float2x2, float2, float2, (0, 1), (0, 1));
+impl_format_matrix!(float2x3, float3, float2, (0, 1), (0, 1, 2));
+impl_format_matrix!(float2x4, float4, float2, (0, 1), (0, 1, 2, 3));
+impl_format_matrix!(float3x2, float2, float3, (0, 1, 2), (0, 1));
+impl_format_matrix!(float3x3, float3, float3, (0, 1, 2), (0, 1, 2));
+impl_format_matrix!(float3x4, float4, float3, (0, 1, 2), (0, 1, 2, 3));
+impl_format_matrix!(float4x2, float2, float4, (0, 1, 2, 3), (0, 1));
+impl_format_matrix!(float4x3, float3, float4, (0, 1, 2, 3), (0, 1, 2));
+impl_format_matrix!(float4x4, float4, float4, (0, 1, 2, 3), (0, 1, 2, 3));
+
+impl_format_matrix!(double2x2, double2, double2, (0, 1), (0, 1));
+impl_format_matrix!(double2x3, double3, double2, (0, 1), (0, 1, 2));
+impl_format_matrix!(double2x4, double4, double2, (0, 1), (0, 1, 2, 3));
+impl_format_matrix!(double3x2, double2, double3, (0, 1, 2), (0, 1));
+impl_format_matrix!(double3x3, double3, double3, (0, 1, 2), (0, 1, 2));
+impl_format_matrix!(double3x4, double4, double3, (0, 1, 2), (0, 1, 2, 3));
+impl_format_matrix!(double4x2, double2, double4, (0, 1, 2, 3), (0, 1));
+impl_format_matrix!(double4x3, double3, double4, (0, 1, 2, 3), (0, 1, 2));
+impl_format_matrix!(double4x4, double4, double4, (0, 1, 2, 3), (0, 1, 2, 3));
@@ -1,15 +1,34 @@
-#![feature(associated_consts, cfg_target_feature, link_llvm_intrinsics, platform_intrinsics, repr_simd, simd_ffi)]
+#![cfg_attr(not(feature = "portable"), feature(asm, associated_consts, cfg_target_feature, link_llvm_intrinsics, platform_intrinsics, repr_simd, simd_ffi, target_feature))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 #![allow(non_camel_case_types)]
 
+#[cfg(not(feature = "std"))]
+extern crate core as std;
+
+#[cfg(feature = "serde")]
+extern crate serde;
+
 #[macro_use] mod macros;
 
+// Only used without `std`, but always built and exported so the tests can check it against `std`
+#[doc(hidden)]
+pub mod math;
+
+pub mod approx;
 pub mod common;
+pub mod format;
+pub mod dispatch;
 pub mod matrix;
 pub mod objc;
 pub mod vector;
 pub mod scalar;
 
+#[cfg(feature = "serde")]
+mod serialization;
+
+pub use approx::*;
 pub use common::*;
+pub use format::*;
 pub use matrix::*;
 pub use vector::*;
@@ -1,11 +1,14 @@
 macro_rules! declare_vector {
-  ($name2:ident, $name3:ident, $name4:ident, $name8:ident, $name16:ident, $scalar:ident, $kind:ident) => (
+  ($name2:ident, $name3:ident, $name4:ident, $name8:ident, $name16:ident, $scalar:ident, $kind:ident, $align2:tt, $align3:tt, $align4:tt, $align8:tt, $align16:tt) => (
     #[repr(C)]
-    #[repr(simd)]
-    #[derive(Copy, Clone, Debug)]
+    #[cfg_attr(not(feature = "portable"), repr(simd))]
+    #[cfg_attr(feature = "portable", repr(align($align2)))]
+    #[derive(Copy, Clone, Debug, Default)]
     pub struct $name2(pub $scalar, pub $scalar);
 
     impl_vector!($name2, $scalar, $kind);
+    impl_lanes!($name2, $scalar, 2);
+    impl_shuffle!($name2, 2);
 
     impl From<isize> for $name2  {
       #[inline(always)]
@@ -22,11 +25,14 @@ macro_rules! declare_vector {
     }
 
     #[repr(C)]
-    #[repr(simd)]
-    #[derive(Copy, Clone, Debug)]
+    #[cfg_attr(not(feature = "portable"), repr(simd))]
+    #[cfg_attr(feature = "portable", repr(align($align3)))]
+    #[derive(Copy, Clone, Debug, Default)]
     pub struct $name3(pub $scalar, pub $scalar, pub $scalar);
 
     impl_vector!($name3, $scalar, $kind);
+    impl_lanes!($name3, $scalar, 3);
+    impl_shuffle!($name3, 3);
 
     impl From<isize> for $name3  {
       #[inline(always)]
@@ -43,11 +49,14 @@ macro_rules! declare_vector {
     }
 
     #[repr(C)]
-    #[repr(simd)]
-    #[derive(Copy, Clone, Debug)]
+    #[cfg_attr(not(feature = "portable"), repr(simd))]
+    #[cfg_attr(feature = "portable", repr(align($align4)))]
+    #[derive(Copy, Clone, Debug, Default)]
     pub struct $name4(pub $scalar, pub $scalar, pub $scalar, pub $scalar);
 
     impl_vector!($name4, $scalar, $kind);
+    impl_lanes!($name4, $scalar, 4);
+    impl_shuffle!($name4, 4);
 
     impl From<isize> for $name4  {
       #[inline(always)]
@@ -64,11 +73,14 @@ macro_rules! declare_vector {
     }
 
     #[repr(C)]
-    #[repr(simd)]
-    #[derive(Copy, Clone, Debug)]
+    #[cfg_attr(not(feature = "portable"), repr(simd))]
+    #[cfg_attr(feature = "portable", repr(align($align8)))]
+    #[derive(Copy, Clone, Debug, Default)]
     pub struct $name8(pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar);
 
     impl_vector!($name8, $scalar, $kind);
+    impl_lanes!($name8, $scalar, 8);
+    impl_shuffle!($name8, 8);
 
     impl From<isize> for $name8  {
       #[inline(always)]
@@ -85,11 +97,14 @@ macro_rules! declare_vector {
     }
 
     #[repr(C)]
-    #[repr(simd)]
-    #[derive(Copy, Clone, Debug)]
+    #[cfg_attr(not(feature = "portable"), repr(simd))]
+    #[cfg_attr(feature = "portable", repr(align($align16)))]
+    #[derive(Copy, Clone, Debug, Default)]
     pub struct $name16(pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar, pub $scalar);
 
     impl_vector!($name16, $scalar, $kind);
+    impl_lanes!($name16, $scalar, 16);
+    impl_shuffle!($name16, 16);
 
     impl From<isize> for $name16  {
       #[inline(always)]
@@ -107,8 +122,299 @@ macro_rules! declare_vector {
   );
 }
 
+macro_rules! impl_lanes {
+  ($vector:ident, $scalar:ident, $lanes:expr) => {
+    impl AsRef<[$scalar]> for $vector {
+      #[inline(always)]
+      fn as_ref(&self) -> &[$scalar] {
+        return unsafe { std::slice::from_raw_parts(self as *const $vector as *const $scalar, $lanes) };
+      }
+    }
+
+    impl AsMut<[$scalar]> for $vector {
+      #[inline(always)]
+      fn as_mut(&mut self) -> &mut [$scalar] {
+        return unsafe { std::slice::from_raw_parts_mut(self as *mut $vector as *mut $scalar, $lanes) };
+      }
+    }
+
+    impl std::ops::Index<usize> for $vector {
+      type Output = $scalar;
+
+      #[inline(always)]
+      fn index(&self, i: usize) -> &$scalar {
+        return &self.as_ref()[i];
+      }
+    }
+
+    impl std::ops::IndexMut<usize> for $vector {
+      #[inline(always)]
+      fn index_mut(&mut self, i: usize) -> &mut $scalar {
+        return &mut self.as_mut()[i];
+      }
+    }
+
+    impl From<[$scalar; $lanes]> for $vector {
+      #[inline(always)]
+      fn from(x: [$scalar; $lanes]) -> $vector {
+        let mut r = $vector::default();
+        r.as_mut().copy_from_slice(&x);
+        return r;
+      }
+    }
+
+    impl From<$vector> for [$scalar; $lanes] {
+      #[inline(always)]
+      fn from(x: $vector) -> [$scalar; $lanes] {
+        let mut r = [0 as $scalar; $lanes];
+        r.copy_from_slice(x.as_ref());
+        return r;
+      }
+    }
+
+    impl IntoIterator for $vector {
+      type Item = $scalar;
+      type IntoIter = Lanes<$vector>;
+
+      #[inline(always)]
+      fn into_iter(self) -> Lanes<$vector> {
+        return Lanes::new(self);
+      }
+    }
+
+    impl std::iter::FromIterator<$scalar> for $vector {
+      fn from_iter<I: IntoIterator<Item=$scalar>>(iter: I) -> $vector {
+        let mut r = $vector::default();
+        let mut n = 0;
+
+        for x in iter {
+          assert!(n < $lanes, "more than {} elements for {}", $lanes, stringify!($vector));
+
+          r[n] = x;
+          n += 1;
+        }
+
+        assert!(n == $lanes, "expected {} elements for {} but got {}", $lanes, stringify!($vector), n);
+
+        return r;
+      }
+    }
+  }
+}
+
+macro_rules! impl_shuffle {
+  ($vector:ident, 2) => {
+    impl Shuffle for $vector {
+      #[inline(always)]
+      fn swap_lanes(self, n: usize) -> Self {
+        match n {
+          1 => return $vector(self.1, self.0),
+          _ => {
+            let mut x = self;
+
+            for i in 0 .. 2 {
+              if i ^ n < 2 {
+                x[i] = self[i ^ n];
+              }
+            }
+
+            return x;
+          }
+        }
+      }
+
+      #[inline(always)]
+      fn shift_lanes_up(self, n: usize, fill: Self) -> Self {
+        match n {
+          0 => return self,
+          1 => return $vector(fill.0, self.0),
+          _ => {
+            let mut x = fill;
+
+            for i in n .. 2 {
+              x[i] = self[i - n];
+            }
+
+            return x;
+          }
+        }
+      }
+    }
+  };
+
+  ($vector:ident, 3) => {
+    impl Shuffle for $vector {
+      #[inline(always)]
+      fn swap_lanes(self, n: usize) -> Self {
+        match n {
+          1 => return $vector(self.1, self.0, self.2),
+          2 => return $vector(self.2, self.1, self.0),
+          _ => {
+            let mut x = self;
+
+            for i in 0 .. 3 {
+              if i ^ n < 3 {
+                x[i] = self[i ^ n];
+              }
+            }
+
+            return x;
+          }
+        }
+      }
+
+      #[inline(always)]
+      fn shift_lanes_up(self, n: usize, fill: Self) -> Self {
+        match n {
+          0 => return self,
+          1 => return $vector(fill.0, self.0, self.1),
+          2 => return $vector(fill.0, fill.1, self.0),
+          _ => {
+            let mut x = fill;
+
+            for i in n .. 3 {
+              x[i] = self[i - n];
+            }
+
+            return x;
+          }
+        }
+      }
+    }
+  };
+
+  ($vector:ident, 4) => {
+    impl Shuffle for $vector {
+      #[inline(always)]
+      fn swap_lanes(self, n: usize) -> Self {
+        match n {
+          1 => return $vector(self.1, self.0, self.3, self.2),
+          2 => return $vector(self.2, self.3, self.0, self.1),
+          _ => {
+            let mut x = self;
+
+            for i in 0 .. 4 {
+              if i ^ n < 4 {
+                x[i] = self[i ^ n];
+              }
+            }
+
+            return x;
+          }
+        }
+      }
+
+      #[inline(always)]
+      fn shift_lanes_up(self, n: usize, fill: Self) -> Self {
+        match n {
+          0 => return self,
+          1 => return $vector(fill.0, self.0, self.1, self.2),
+          2 => return $vector(fill.0, fill.1, self.0, self.1),
+          _ => {
+            let mut x = fill;
+
+            for i in n .. 4 {
+              x[i] = self[i - n];
+            }
+
+            return x;
+          }
+        }
+      }
+    }
+  };
+
+  ($vector:ident, 8) => {
+    impl Shuffle for $vector {
+      #[inline(always)]
+      fn swap_lanes(self, n: usize) -> Self {
+        match n {
+          1 => return $vector(self.1, self.0, self.3, self.2, self.5, self.4, self.7, self.6),
+          2 => return $vector(self.2, self.3, self.0, self.1, self.6, self.7, self.4, self.5),
+          4 => return $vector(self.4, self.5, self.6, self.7, self.0, self.1, self.2, self.3),
+          _ => {
+            let mut x = self;
+
+            for i in 0 .. 8 {
+              if i ^ n < 8 {
+                x[i] = self[i ^ n];
+              }
+            }
+
+            return x;
+          }
+        }
+      }
+
+      #[inline(always)]
+      fn shift_lanes_up(self, n: usize, fill: Self) -> Self {
+        match n {
+          0 => return self,
+          1 => return $vector(fill.0, self.0, self.1, self.2, self.3, self.4, self.5, self.6),
+          2 => return $vector(fill.0, fill.1, self.0, self.1, self.2, self.3, self.4, self.5),
+          4 => return $vector(fill.0, fill.1, fill.2, fill.3, self.0, self.1, self.2, self.3),
+          _ => {
+            let mut x = fill;
+
+            for i in n .. 8 {
+              x[i] = self[i - n];
+            }
+
+            return x;
+          }
+        }
+      }
+    }
+  };
+
+  ($vector:ident, 16) => {
+    impl Shuffle for $vector {
+      #[inline(always)]
+      fn swap_lanes(self, n: usize) -> Self {
+        match n {
+          1 => return $vector(self.1, self.0, self.3, self.2, self.5, self.4, self.7, self.6, self.9, self.8, self.11, self.10, self.13, self.12, self.15, self.14),
+          2 => return $vector(self.2, self.3, self.0, self.1, self.6, self.7, self.4, self.5, self.10, self.11, self.8, self.9, self.14, self.15, self.12, self.13),
+          4 => return $vector(self.4, self.5, self.6, self.7, self.0, self.1, self.2, self.3, self.12, self.13, self.14, self.15, self.8, self.9, self.10, self.11),
+          8 => return $vector(self.8, self.9, self.10, self.11, self.12, self.13, self.14, self.15, self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7),
+          _ => {
+            let mut x = self;
+
+            for i in 0 .. 16 {
+              if i ^ n < 16 {
+                x[i] = self[i ^ n];
+              }
+            }
+
+            return x;
+          }
+        }
+      }
+
+      #[inline(always)]
+      fn shift_lanes_up(self, n: usize, fill: Self) -> Self {
+        match n {
+          0 => return self,
+          1 => return $vector(fill.0, self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7, self.8, self.9, self.10, self.11, self.12, self.13, self.14),
+          2 => return $vector(fill.0, fill.1, self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7, self.8, self.9, self.10, self.11, self.12, self.13),
+          4 => return $vector(fill.0, fill.1, fill.2, fill.3, self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7, self.8, self.9, self.10, self.11),
+          8 => return $vector(fill.0, fill.1, fill.2, fill.3, fill.4, fill.5, fill.6, fill.7, self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7),
+          _ => {
+            let mut x = fill;
+
+            for i in n .. 16 {
+              x[i] = self[i - n];
+            }
+
+            return x;
+          }
+        }
+      }
+    }
+  };
+}
+
 macro_rules! impl_trait {
-  ($vector:ident, $scalar:ident, $intrinsic:ident, $trait_name:ident, $fn_name:ident) => {
+  ($vector:ident, $scalar:ident, $intrinsic:ident, $trait_name:ident, $fn_name:ident, $assign_trait_name:ident, $assign_fn_name:ident) => {
     impl $trait_name<$vector> for $vector {
       type Output = Self;
 
@@ -135,6 +441,20 @@ macro_rules! impl_trait {
         return unsafe { $intrinsic(broadcast(self), other) };
       }
     }
+
+    impl $assign_trait_name<$vector> for $vector {
+      #[inline(always)]
+      fn $assign_fn_name(&mut self, other: Self) {
+        *self = unsafe { $intrinsic(*self, other) };
+      }
+    }
+
+    impl $assign_trait_name<$scalar> for $vector {
+      #[inline(always)]
+      fn $assign_fn_name(&mut self, other: $scalar) {
+        *self = unsafe { $intrinsic(*self, broadcast(other)) };
+      }
+    }
   }
 }
 
@@ -142,12 +462,12 @@ macro_rules! impl_vector {
   ($vector:ident, $scalar:ident, integer) => {
     impl_vector!($vector, $scalar, common);
 
-    impl_trait!($vector, $scalar, simd_and, BitAnd, bitand);
-    impl_trait!($vector, $scalar, simd_or, BitOr, bitor);
-    impl_trait!($vector, $scalar, simd_xor, BitXor, bitxor);
+    impl_trait!($vector, $scalar, simd_and, BitAnd, bitand, BitAndAssign, bitand_assign);
+    impl_trait!($vector, $scalar, simd_or, BitOr, bitor, BitOrAssign, bitor_assign);
+    impl_trait!($vector, $scalar, simd_xor, BitXor, bitxor, BitXorAssign, bitxor_assign);
 
-    impl_trait!($vector, $scalar, simd_shl, Shl, shl);
-    impl_trait!($vector, $scalar, simd_shr, Shr, shr);
+    impl_trait!($vector, $scalar, simd_shl, Shl, shl, ShlAssign, shl_assign);
+    impl_trait!($vector, $scalar, simd_shr, Shr, shr, ShrAssign, shr_assign);
 
     impl std::ops::Rem for $vector {
       type Output = Self;
@@ -157,6 +477,65 @@ macro_rules! impl_vector {
         return self - (self / other) * other;
       }
     }
+
+    impl std::ops::RemAssign for $vector {
+      #[inline(always)]
+      fn rem_assign(&mut self, other: Self) {
+        *self = *self % other;
+      }
+    }
+
+    impl Eq for $vector {
+    }
+
+    impl std::hash::Hash for $vector {
+      #[inline]
+      fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for i in 0 .. Self::LANES {
+          Vector::extract(*self, i as u32).hash(state);
+        }
+      }
+    }
+
+    impl PartialOrd for $vector {
+      #[inline(always)]
+      fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        return Some(Ord::cmp(self, other));
+      }
+    }
+
+    impl Ord for $vector {
+      #[inline]
+      fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        for i in 0 .. Self::LANES {
+          let ordering = Ord::cmp(&Vector::extract(*self, i as u32), &Vector::extract(*other, i as u32));
+
+          if ordering != std::cmp::Ordering::Equal {
+            return ordering;
+          }
+        }
+
+        return std::cmp::Ordering::Equal;
+      }
+    }
+
+    // `Ord` has its own `max`, `min` and `clamp`, inherent methods win the lookup so these keep meaning the lane wise ones
+    impl $vector {
+      #[inline(always)]
+      pub fn max(self, other: Self) -> Self {
+        return Vector::max(self, other);
+      }
+
+      #[inline(always)]
+      pub fn min(self, other: Self) -> Self {
+        return Vector::min(self, other);
+      }
+
+      #[inline(always)]
+      pub fn clamp(self, min: Self, max: Self) -> Self {
+        return Vector::clamp(self, min, max);
+      }
+    }
   };
   ($vector:ident, $scalar:ident, signed) => {
     impl_vector!($vector, $scalar, integer);
@@ -186,10 +565,10 @@ macro_rules! impl_vector {
     impl_vector!($vector, $scalar, common);
   };
   ($vector:ident, $scalar:ident, common) => {
-    impl_trait!($vector, $scalar, simd_add, Add, add);
-    impl_trait!($vector, $scalar, simd_sub, Sub, sub);
-    impl_trait!($vector, $scalar, simd_mul, Mul, mul);
-    impl_trait!($vector, $scalar, simd_div, Div, div);
+    impl_trait!($vector, $scalar, simd_add, Add, add, AddAssign, add_assign);
+    impl_trait!($vector, $scalar, simd_sub, Sub, sub, SubAssign, sub_assign);
+    impl_trait!($vector, $scalar, simd_mul, Mul, mul, MulAssign, mul_assign);
+    impl_trait!($vector, $scalar, simd_div, Div, div, DivAssign, div_assign);
 
     impl std::ops::Neg for $vector {
       type Output = Self;
@@ -200,6 +579,34 @@ macro_rules! impl_vector {
       }
     }
 
+    impl std::iter::Sum for $vector {
+      #[inline]
+      fn sum<I: Iterator<Item=$vector>>(iter: I) -> $vector {
+        return iter.fold(Self::from(0), |a, b| a + b);
+      }
+    }
+
+    impl<'a> std::iter::Sum<&'a $vector> for $vector {
+      #[inline]
+      fn sum<I: Iterator<Item=&'a $vector>>(iter: I) -> $vector {
+        return iter.fold(Self::from(0), |a, b| a + *b);
+      }
+    }
+
+    impl std::iter::Product for $vector {
+      #[inline]
+      fn product<I: Iterator<Item=$vector>>(iter: I) -> $vector {
+        return iter.fold(Self::from(1), |a, b| a * b);
+      }
+    }
+
+    impl<'a> std::iter::Product<&'a $vector> for $vector {
+      #[inline]
+      fn product<I: Iterator<Item=&'a $vector>>(iter: I) -> $vector {
+        return iter.fold(Self::from(1), |a, b| a * *b);
+      }
+    }
+
     impl PartialEq for $vector {
       #[inline(always)]
       fn eq(&self, other: &Self) -> bool {
@@ -214,8 +621,165 @@ macro_rules! impl_vector {
   }
 }
 
+macro_rules! impl_lane {
+  ($scalar:ident, $wide:ident, $from:ident) => {
+    impl Lane for $scalar {
+      #[inline(always)]
+      fn mask(x: bool) -> Self {
+        return if x { !0 } else { 0 };
+      }
+
+      #[inline(always)]
+      fn cast<T: Lane>(self) -> T {
+        return T::$from(self as $wide);
+      }
+
+      #[inline(always)]
+      fn from_i64(x: i64) -> Self {
+        return x as $scalar;
+      }
+
+      #[inline(always)]
+      fn from_u64(x: u64) -> Self {
+        return x as $scalar;
+      }
+
+      #[inline(always)]
+      fn from_f64(x: f64) -> Self {
+        return x as $scalar;
+      }
+
+      #[inline(always)]
+      fn lane_add(x: Self, y: Self) -> Self {
+        return x.wrapping_add(y);
+      }
+
+      #[inline(always)]
+      fn lane_sub(x: Self, y: Self) -> Self {
+        return x.wrapping_sub(y);
+      }
+
+      #[inline(always)]
+      fn lane_mul(x: Self, y: Self) -> Self {
+        return x.wrapping_mul(y);
+      }
+
+      #[inline(always)]
+      fn lane_div(x: Self, y: Self) -> Self {
+        return x.wrapping_div(y);
+      }
+
+      #[inline(always)]
+      fn lane_shl(x: Self, y: Self) -> Self {
+        return x.wrapping_shl(y as u32);
+      }
+
+      #[inline(always)]
+      fn lane_shr(x: Self, y: Self) -> Self {
+        return x.wrapping_shr(y as u32);
+      }
+
+      #[inline(always)]
+      fn lane_and(x: Self, y: Self) -> Self {
+        return x & y;
+      }
+
+      #[inline(always)]
+      fn lane_or(x: Self, y: Self) -> Self {
+        return x | y;
+      }
+
+      #[inline(always)]
+      fn lane_xor(x: Self, y: Self) -> Self {
+        return x ^ y;
+      }
+    }
+  };
+  ($scalar:ident, float) => {
+    impl Lane for $scalar {
+      #[inline(always)]
+      fn mask(x: bool) -> Self {
+        return $scalar::from_bits(if x { !0 } else { 0 });
+      }
+
+      #[inline(always)]
+      fn cast<T: Lane>(self) -> T {
+        return T::from_f64(self as f64);
+      }
+
+      #[inline(always)]
+      fn from_i64(x: i64) -> Self {
+        return x as $scalar;
+      }
+
+      #[inline(always)]
+      fn from_u64(x: u64) -> Self {
+        return x as $scalar;
+      }
+
+      #[inline(always)]
+      fn from_f64(x: f64) -> Self {
+        return x as $scalar;
+      }
+
+      #[inline(always)]
+      fn lane_add(x: Self, y: Self) -> Self {
+        return x + y;
+      }
+
+      #[inline(always)]
+      fn lane_sub(x: Self, y: Self) -> Self {
+        return x - y;
+      }
+
+      #[inline(always)]
+      fn lane_mul(x: Self, y: Self) -> Self {
+        return x * y;
+      }
+
+      #[inline(always)]
+      fn lane_div(x: Self, y: Self) -> Self {
+        return x / y;
+      }
+
+      #[inline(always)]
+      fn lane_shl(_: Self, _: Self) -> Self {
+        unreachable!();
+      }
+
+      #[inline(always)]
+      fn lane_shr(_: Self, _: Self) -> Self {
+        unreachable!();
+      }
+
+      #[inline(always)]
+      fn lane_and(x: Self, y: Self) -> Self {
+        return $scalar::from_bits(x.to_bits() & y.to_bits());
+      }
+
+      #[inline(always)]
+      fn lane_or(x: Self, y: Self) -> Self {
+        return $scalar::from_bits(x.to_bits() | y.to_bits());
+      }
+
+      #[inline(always)]
+      fn lane_xor(x: Self, y: Self) -> Self {
+        return $scalar::from_bits(x.to_bits() ^ y.to_bits());
+      }
+    }
+  }
+}
+
 macro_rules! impl_scalar {
   ($scalar:ident, signed) => {
+    impl_lane!($scalar, i64, from_i64);
+    impl_scalar!($scalar, integer);
+  };
+  ($scalar:ident, unsigned) =>  {
+    impl_lane!($scalar, u64, from_u64);
+    impl_scalar!($scalar, integer);
+  };
+  ($scalar:ident, integer) => {
     impl Scalar for $scalar {
       #[inline(always)]
       fn max(x: Self, y: Self) -> Self {
@@ -232,10 +796,9 @@ macro_rules! impl_scalar {
       const ZERO: $scalar = 0;
     }
   };
-  ($scalar:ident, unsigned) =>  {
-    impl_scalar!($scalar, signed);
-  };
   ($scalar:ident, float) => {
+    impl_lane!($scalar, float);
+
     impl Scalar for $scalar {
       #[inline(always)]
       fn max(x: Self, y: Self) -> Self {
@@ -249,40 +812,127 @@ macro_rules! impl_scalar {
     }
 
     impl FloatScalar for $scalar {
+      #[cfg(feature = "std")]
       #[inline(always)]
       fn sqrt(x: Self) -> Self {
         return x.sqrt();
       }
 
+      #[cfg(not(feature = "std"))]
+      #[inline(always)]
+      fn sqrt(x: Self) -> Self {
+        return ::math::sqrt(x as f64) as $scalar;
+      }
+
+      #[cfg(feature = "std")]
       #[inline(always)]
       fn fract(x: Self) -> Self {
         return x.fract();
       }
 
+      #[cfg(not(feature = "std"))]
+      #[inline(always)]
+      fn fract(x: Self) -> Self {
+        return ::math::fract(x as f64) as $scalar;
+      }
+
+      #[cfg(feature = "std")]
       #[inline(always)]
       fn ceil(x: Self) -> Self {
         return x.ceil();
       }
 
+      #[cfg(not(feature = "std"))]
+      #[inline(always)]
+      fn ceil(x: Self) -> Self {
+        return ::math::ceil(x as f64) as $scalar;
+      }
+
+      #[cfg(feature = "std")]
       #[inline(always)]
       fn floor(x: Self) -> Self {
         return x.floor();
       }
 
+      #[cfg(not(feature = "std"))]
+      #[inline(always)]
+      fn floor(x: Self) -> Self {
+        return ::math::floor(x as f64) as $scalar;
+      }
+
+      #[cfg(feature = "std")]
       #[inline(always)]
       fn trunc(x: Self) -> Self {
         return x.trunc();
       }
 
+      #[cfg(not(feature = "std"))]
+      #[inline(always)]
+      fn trunc(x: Self) -> Self {
+        return ::math::trunc(x as f64) as $scalar;
+      }
+
+      #[cfg(feature = "std")]
       #[inline(always)]
       fn sin(x: Self) -> Self {
         return x.sin();
       }
 
+      #[cfg(not(feature = "std"))]
       #[inline(always)]
-      fn cos(x: Self) -> Self{
+      fn sin(x: Self) -> Self {
+        return ::math::sin(x as f64) as $scalar;
+      }
+
+      #[cfg(feature = "std")]
+      #[inline(always)]
+      fn cos(x: Self) -> Self {
         return x.cos();
       }
+
+      #[cfg(not(feature = "std"))]
+      #[inline(always)]
+      fn cos(x: Self) -> Self {
+        return ::math::cos(x as f64) as $scalar;
+      }
+
+      #[cfg(feature = "std")]
+      #[inline(always)]
+      fn tan(x: Self) -> Self {
+        return x.tan();
+      }
+
+      #[cfg(not(feature = "std"))]
+      #[inline(always)]
+      fn tan(x: Self) -> Self {
+        return ::math::tan(x as f64) as $scalar;
+      }
+
+      #[cfg(feature = "std")]
+      #[inline(always)]
+      fn sin_cos(x: Self) -> (Self, Self) {
+        return x.sin_cos();
+      }
+
+      #[cfg(not(feature = "std"))]
+      #[inline(always)]
+      fn sin_cos(x: Self) -> (Self, Self) {
+        let (s, c) = ::math::sin_cos(x as f64);
+
+        return (s as $scalar, c as $scalar);
+      }
+
+      #[cfg(feature = "std")]
+      #[inline(always)]
+      fn fma(x: Self, y: Self, z: Self) -> Self {
+        return x.mul_add(y, z);
+      }
+
+      #[cfg(not(feature = "std"))]
+      #[inline(always)]
+      fn fma(x: Self, y: Self, z: Self) -> Self {
+        return ::math::Fma::fma(x, y, z);
+      }
     }
   }
 }
@@ -290,15 +940,15 @@ macro_rules! impl_scalar {
 macro_rules! declare_matrix {
   ($name2:ident, $name3:ident, $name4:ident, $vector:ty) => (
     #[repr(C)]
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, Default)]
     pub struct $name2(pub $vector, pub $vector);
 
     #[repr(C)]
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, Default)]
     pub struct $name3(pub $vector, pub $vector, pub $vector);
 
     #[repr(C)]
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, Default)]
     pub struct $name4(pub $vector, pub $vector, pub $vector, pub $vector);
   );
 }
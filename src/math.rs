@@ -0,0 +1,386 @@
+// Scalar math for builds without `std`, everything is done in double precision and the `f32` versions round the result
+// afterwards, which is exact for `trunc` / `floor` / `ceil` / `fract` and still correctly rounded for `sqrt`.
+
+use std;
+use std::f64::consts::FRAC_2_PI;
+
+const EXPONENT_BIAS: i64 = 1023;
+const MANTISSA_BITS: i64 = 52;
+
+#[inline]
+pub fn trunc(x: f64) -> f64 {
+  let bits = x.to_bits();
+  let e = ((bits >> MANTISSA_BITS) & 0x7FF) as i64 - EXPONENT_BIAS;
+
+  if e >= MANTISSA_BITS {
+    return x;
+  }
+
+  if e < 0 {
+    return f64::from_bits(bits & (1 << 63));
+  }
+
+  return f64::from_bits(bits & !((1u64 << (MANTISSA_BITS - e)) - 1));
+}
+
+#[inline]
+pub fn floor(x: f64) -> f64 {
+  let t = trunc(x);
+
+  return if t > x { t - 1.0 } else { t };
+}
+
+#[inline]
+pub fn ceil(x: f64) -> f64 {
+  let t = trunc(x);
+
+  return if t < x { t + 1.0 } else { t };
+}
+
+#[inline]
+pub fn fract(x: f64) -> f64 {
+  return x - trunc(x);
+}
+
+// Digit by digit square root on the integer mantissa, the remainder goes into a sticky bit so that the final conversion
+// back to floating point rounds to nearest even like the hardware instruction.
+pub fn sqrt(x: f64) -> f64 {
+  if x.is_nan() || x == 0.0 || x == std::f64::INFINITY {
+    return x;
+  }
+
+  if x < 0.0 {
+    return std::f64::NAN;
+  }
+
+  let bits = x.to_bits();
+  let mut e = ((bits >> MANTISSA_BITS) & 0x7FF) as i64;
+  let mut m = bits & ((1 << MANTISSA_BITS) - 1);
+
+  if e == 0 {
+    while m & (1 << MANTISSA_BITS) == 0 {
+      m <<= 1;
+      e -= 1;
+    }
+
+    e += 1;
+  } else {
+    m |= 1 << MANTISSA_BITS;
+  }
+
+  // x = m * 2^e with an even exponent
+  e -= EXPONENT_BIAS + MANTISSA_BITS;
+
+  if e & 1 != 0 {
+    m <<= 1;
+    e -= 1;
+  }
+
+  let n = (m as u128) << 56;
+  let mut q: u128 = 0;
+  let mut r = n;
+  let mut b: u128 = 1 << 126;
+
+  while b > n {
+    b >>= 2;
+  }
+
+  while b != 0 {
+    if r >= q + b {
+      r -= q + b;
+      q = (q >> 1) + b;
+    } else {
+      q >>= 1;
+    }
+
+    b >>= 2;
+  }
+
+  if r != 0 {
+    q |= 1;
+  }
+
+  return scale(q as f64, e / 2 - 28);
+}
+
+#[inline]
+fn scale(x: f64, n: i64) -> f64 {
+  let mut x = x;
+  let mut n = n;
+
+  while n != 0 {
+    let k = if n > 1000 { 1000 } else if n < -1000 { -1000 } else { n };
+
+    x *= f64::from_bits(((k + EXPONENT_BIAS) as u64) << MANTISSA_BITS);
+    n -= k;
+  }
+
+  return x;
+}
+
+// π/2 split in three parts, the first two have trailing zeros so that `n * PIO2_1` and `n * PIO2_2` are exact
+const PIO2_1: f64 = 1.57079632673412561417e+00;
+const PIO2_2: f64 = 6.07710050630396597660e-11;
+const PIO2_3: f64 = 2.02226624871116645580e-21;
+
+const S1: f64 = -1.66666666666666324348e-01;
+const S2: f64 = 8.33333333332248946124e-03;
+const S3: f64 = -1.98412698298579493134e-04;
+const S4: f64 = 2.75573137070700676789e-06;
+const S5: f64 = -2.50507602534068634195e-08;
+const S6: f64 = 1.58969099521155010221e-10;
+
+const C1: f64 = 4.16666666666666019037e-02;
+const C2: f64 = -1.38888888888741095749e-03;
+const C3: f64 = 2.48015872894767294178e-05;
+const C4: f64 = -2.75573143513906633035e-07;
+const C5: f64 = 2.08757232129817482790e-09;
+const C6: f64 = -1.13596475577881948265e-11;
+
+#[inline]
+fn sin_kernel(x: f64) -> f64 {
+  let z = x * x;
+  let r = S2 + z * (S3 + z * (S4 + z * (S5 + z * S6)));
+
+  return x + z * x * (S1 + z * r);
+}
+
+#[inline]
+fn cos_kernel(x: f64) -> f64 {
+  let z = x * x;
+  let r = z * (C1 + z * (C2 + z * (C3 + z * (C4 + z * (C5 + z * C6)))));
+  let h = 0.5 * z;
+  let w = 1.0 - h;
+
+  return w + (((1.0 - w) - h) + z * r);
+}
+
+// Reduces x to [-π/4, π/4] and returns the quadrant, the reduction loses precision once |x| gets past ~2^20
+#[inline]
+fn reduce(x: f64) -> (f64, i64) {
+  let n = floor(x * FRAC_2_PI + 0.5);
+
+  return (((x - n * PIO2_1) - n * PIO2_2) - n * PIO2_3, n as i64);
+}
+
+pub fn sin(x: f64) -> f64 {
+  if !x.is_finite() {
+    return std::f64::NAN;
+  }
+
+  let (y, n) = reduce(x);
+
+  return match n & 3 {
+    0 => sin_kernel(y),
+    1 => cos_kernel(y),
+    2 => -sin_kernel(y),
+    _ => -cos_kernel(y)
+  };
+}
+
+pub fn cos(x: f64) -> f64 {
+  if !x.is_finite() {
+    return std::f64::NAN;
+  }
+
+  let (y, n) = reduce(x);
+
+  return match n & 3 {
+    0 => cos_kernel(y),
+    1 => -sin_kernel(y),
+    2 => -cos_kernel(y),
+    _ => sin_kernel(y)
+  };
+}
+
+pub fn tan(x: f64) -> f64 {
+  if !x.is_finite() {
+    return std::f64::NAN;
+  }
+
+  let (y, n) = reduce(x);
+
+  return if n & 1 == 0 { sin_kernel(y) / cos_kernel(y) } else { -cos_kernel(y) / sin_kernel(y) };
+}
+
+pub fn sin_cos(x: f64) -> (f64, f64) {
+  if !x.is_finite() {
+    return (std::f64::NAN, std::f64::NAN);
+  }
+
+  let (y, n) = reduce(x);
+  let (s, c) = (sin_kernel(y), cos_kernel(y));
+
+  return match n & 3 {
+    0 => (s, c),
+    1 => (c, -s),
+    2 => (-s, -c),
+    _ => (-c, s)
+  };
+}
+
+// Splits a finite double into m * 2^e with an integer m
+#[inline]
+fn decompose(x: f64) -> (bool, i64, u64) {
+  let bits = x.to_bits();
+  let e = ((bits >> MANTISSA_BITS) & 0x7FF) as i64;
+  let m = bits & ((1 << MANTISSA_BITS) - 1);
+
+  if e == 0 {
+    return (bits >> 63 != 0, 1 - EXPONENT_BIAS - MANTISSA_BITS, m);
+  }
+
+  return (bits >> 63 != 0, e - EXPONENT_BIAS - MANTISSA_BITS, m | (1 << MANTISSA_BITS));
+}
+
+// Shifts right and ors everything shifted out into the lowest bit, which is enough for rounding as long as it stays
+// well below the rounding position
+#[inline]
+fn shift_sticky(x: u128, n: i64) -> u128 {
+  if n == 0 {
+    return x;
+  }
+
+  if n >= 128 {
+    return (x != 0) as u128;
+  }
+
+  return (x >> n) | ((x << (128 - n)) != 0) as u128;
+}
+
+// Correctly rounded x * y + z on the integer mantissas, the exact product has at most 106 bits so everything is aligned
+// with the leading bit at 125 and the sum fits into 128 bits
+pub fn fma(x: f64, y: f64, z: f64) -> f64 {
+  if x.is_nan() || y.is_nan() || z.is_nan() || x == 0.0 || y == 0.0 {
+    return x * y + z;
+  }
+
+  let infinity = std::f64::INFINITY;
+
+  if x == infinity || x == -infinity || y == infinity || y == -infinity {
+    return x * y + z;
+  }
+
+  if z == infinity || z == -infinity {
+    return z;
+  }
+
+  let (sx, ex, mx) = decompose(x);
+  let (sy, ey, my) = decompose(y);
+  let (sz, ez, mz) = decompose(z);
+
+  let mut p = (mx as u128) * (my as u128);
+  let mut ep = ex + ey;
+  let sp = sx != sy;
+
+  let shift = p.leading_zeros() as i64 - 2;
+  p <<= shift;
+  ep -= shift;
+
+  if mz == 0 {
+    return round(sp, p, ep);
+  }
+
+  let mut q = mz as u128;
+  let mut eq = ez;
+
+  let shift = q.leading_zeros() as i64 - 2;
+  q <<= shift;
+  eq -= shift;
+
+  let (sa, a, ea, sb, b) = if ep >= eq { (sp, p, ep, sz, shift_sticky(q, ep - eq)) } else { (sz, q, eq, sp, shift_sticky(p, eq - ep)) };
+
+  if sa == sb {
+    return round(sa, a + b, ea);
+  }
+
+  if a == b {
+    return 0.0;
+  }
+
+  return if a > b { round(sa, a - b, ea) } else { round(sb, b - a, ea) };
+}
+
+// Rounds m * 2^e to the nearest double, ties to even
+#[inline]
+fn round(sign: bool, m: u128, e: i64) -> f64 {
+  let top = 127 - m.leading_zeros() as i64;
+  let mut unit = top + e - MANTISSA_BITS;
+
+  if unit < 1 - EXPONENT_BIAS - MANTISSA_BITS {
+    unit = 1 - EXPONENT_BIAS - MANTISSA_BITS;
+  }
+
+  let shift = unit - e;
+  let mut r = if shift <= 0 {
+    (m << -shift) as u64
+  } else if shift >= 128 {
+    0
+  } else {
+    let r = (m >> shift) as u64;
+    let rest = m & ((1 << shift) - 1);
+    let half = 1u128 << (shift - 1);
+
+    if rest > half || (rest == half && r & 1 != 0) { r + 1 } else { r }
+  };
+
+  if r >> (MANTISSA_BITS + 1) != 0 {
+    r >>= 1;
+    unit += 1;
+  }
+
+  let sign = (sign as u64) << 63;
+
+  if r >> MANTISSA_BITS == 0 {
+    return f64::from_bits(sign | r);
+  }
+
+  let exponent = unit + MANTISSA_BITS + EXPONENT_BIAS;
+
+  if exponent >= 0x7FF {
+    return f64::from_bits(sign | std::f64::INFINITY.to_bits());
+  }
+
+  return f64::from_bits(sign | ((exponent as u64) << MANTISSA_BITS) | (r & ((1 << MANTISSA_BITS) - 1)));
+}
+
+// The product of two floats is exact as a double, and rounding the sum to odd before rounding to float avoids the
+// double rounding error since a double has more than two extra bits
+pub fn fma_f32(x: f32, y: f32, z: f32) -> f32 {
+  let p = x as f64 * y as f64;
+  let z = z as f64;
+  let s = p + z;
+
+  if !s.is_finite() {
+    return s as f32;
+  }
+
+  let t = s - p;
+  let error = (p - (s - t)) + (z - t);
+
+  if error != 0.0 && s.to_bits() & 1 == 0 {
+    let bits = if (error > 0.0) == (s > 0.0) { s.to_bits() + 1 } else { s.to_bits() - 1 };
+
+    return f64::from_bits(bits) as f32;
+  }
+
+  return s as f32;
+}
+
+pub trait Fma {
+  fn fma(x: Self, y: Self, z: Self) -> Self;
+}
+
+impl Fma for f32 {
+  #[inline(always)]
+  fn fma(x: f32, y: f32, z: f32) -> f32 {
+    return fma_f32(x, y, z);
+  }
+}
+
+impl Fma for f64 {
+  #[inline(always)]
+  fn fma(x: f64, y: f64, z: f64) -> f64 {
+    return fma(x, y, z);
+  }
+}
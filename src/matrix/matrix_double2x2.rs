@@ -1,6 +1,7 @@
 use std;
 use ::*;
 
+#[cfg(not(feature = "portable"))]
 extern {
   fn __invert_d2(a: double2x2) -> double2x2;
 }
@@ -23,6 +24,15 @@ impl std::ops::Sub for double2x2 {
   }
 }
 
+impl std::ops::Neg for double2x2 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn neg(self) -> Self {
+    return double2x2(-self.0, -self.1);
+  }
+}
+
 impl std::ops::Mul<double2x2> for double2x2 {
   type Output = double2x2;
 
@@ -32,6 +42,24 @@ impl std::ops::Mul<double2x2> for double2x2 {
   }
 }
 
+impl std::ops::Mul<double3x2> for double2x2 {
+  type Output = double3x2;
+
+  #[inline(always)]
+  fn mul(self, other: double3x2) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
+impl std::ops::Mul<double4x2> for double2x2 {
+  type Output = double4x2;
+
+  #[inline(always)]
+  fn mul(self, other: double4x2) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<double2> for double2x2 {
   type Output = double2;
 
@@ -52,6 +80,89 @@ impl std::ops::Mul<f64> for double2x2 {
   }
 }
 
+impl std::ops::Div<f64> for double2x2 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn div(self, other: f64) -> Self {
+    let a = double2::broadcast(other);
+
+    return double2x2(self.0 / a, self.1 / a);
+  }
+}
+
+impl std::ops::Mul<double2x2> for double2 {
+  type Output = double2;
+
+  #[inline(always)]
+  fn mul(self, other: double2x2) -> Self::Output {
+    return other.transpose().dot(self);
+  }
+}
+
+impl std::ops::AddAssign for double2x2 {
+  #[inline(always)]
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl std::ops::SubAssign for double2x2 {
+  #[inline(always)]
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl std::ops::MulAssign for double2x2 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: Self) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::MulAssign<f64> for double2x2 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: f64) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::DivAssign<f64> for double2x2 {
+  #[inline(always)]
+  fn div_assign(&mut self, other: f64) {
+    *self = *self / other;
+  }
+}
+
+impl std::iter::Sum for double2x2 {
+  #[inline]
+  fn sum<I: Iterator<Item=double2x2>>(iter: I) -> double2x2 {
+    return iter.fold(double2x2(double2::from(0), double2::from(0)), |a, b| a + b);
+  }
+}
+
+impl<'a> std::iter::Sum<&'a double2x2> for double2x2 {
+  #[inline]
+  fn sum<I: Iterator<Item=&'a double2x2>>(iter: I) -> double2x2 {
+    return iter.fold(double2x2(double2::from(0), double2::from(0)), |a, b| a + *b);
+  }
+}
+
+impl std::iter::Product for double2x2 {
+  #[inline]
+  fn product<I: Iterator<Item=double2x2>>(iter: I) -> double2x2 {
+    return iter.fold(double2x2::identity(), |a, b| a * b);
+  }
+}
+
+impl<'a> std::iter::Product<&'a double2x2> for double2x2 {
+  #[inline]
+  fn product<I: Iterator<Item=&'a double2x2>>(iter: I) -> double2x2 {
+    return iter.fold(double2x2::identity(), |a, b| a * *b);
+  }
+}
+
 impl Dot<double2x2> for double2x2 {
   type DotProduct = double2x2;
 
@@ -61,6 +172,24 @@ impl Dot<double2x2> for double2x2 {
   }
 }
 
+impl Dot<double3x2> for double2x2 {
+  type DotProduct = double3x2;
+
+  #[inline(always)]
+  fn dot(self, other: double3x2) -> Self::DotProduct {
+    return double3x2(self.dot(other.0), self.dot(other.1), self.dot(other.2));
+  }
+}
+
+impl Dot<double4x2> for double2x2 {
+  type DotProduct = double4x2;
+
+  #[inline(always)]
+  fn dot(self, other: double4x2) -> Self::DotProduct {
+    return double4x2(self.dot(other.0), self.dot(other.1), self.dot(other.2), self.dot(other.3));
+  }
+}
+
 impl Dot<double2> for double2x2 {
   type DotProduct = double2;
 
@@ -77,6 +206,135 @@ impl PartialEq for double2x2 {
   }
 }
 
+impl std::ops::Index<usize> for double2x2 {
+  type Output = double2;
+
+  #[inline]
+  fn index(&self, index: usize) -> &double2 {
+    match index {
+      0 => return &self.0,
+      1 => return &self.1,
+      _ => panic!("column index {} out of range for double2x2", index)
+    }
+  }
+}
+
+impl std::ops::IndexMut<usize> for double2x2 {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut double2 {
+    match index {
+      0 => return &mut self.0,
+      1 => return &mut self.1,
+      _ => panic!("column index {} out of range for double2x2", index)
+    }
+  }
+}
+
+impl std::ops::Index<(usize, usize)> for double2x2 {
+  type Output = f64;
+
+  #[inline(always)]
+  fn index(&self, index: (usize, usize)) -> &f64 {
+    return &self[index.1][index.0];
+  }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for double2x2 {
+  #[inline(always)]
+  fn index_mut(&mut self, index: (usize, usize)) -> &mut f64 {
+    return &mut self[index.1][index.0];
+  }
+}
+
+impl From<[[f64; 2]; 2]> for double2x2 {
+  #[inline(always)]
+  fn from(x: [[f64; 2]; 2]) -> double2x2 {
+    return double2x2(double2::from(x[0]), double2::from(x[1]));
+  }
+}
+
+impl From<double2x2> for [[f64; 2]; 2] {
+  #[inline(always)]
+  fn from(x: double2x2) -> [[f64; 2]; 2] {
+    return [x.0.into(), x.1.into()];
+  }
+}
+
+impl From<float2x2> for double2x2 {
+  #[inline(always)]
+  fn from(x: float2x2) -> double2x2 {
+    return x.to_double();
+  }
+}
+
+impl Determinant for double2x2 {
+  type Scalar = f64;
+
+  #[inline(always)]
+  fn determinant(self) -> f64 {
+    return (self.0).0 * (self.1).1 - (self.0).1 * (self.1).0;
+  }
+}
+
+impl Matrix for double2x2 {
+  type Scalar = f64;
+  type Column = double2;
+  type Row = double2;
+  type Transpose = double2x2;
+  type FloatMatrix = float2x2;
+  type DoubleMatrix = double2x2;
+
+  const ROWS: usize = 2;
+  const COLUMNS: usize = 2;
+
+  #[inline(always)]
+  fn column(self, index: usize) -> double2 {
+    return self[index];
+  }
+
+  #[inline(always)]
+  fn row(self, index: usize) -> double2 {
+    return double2(self.0[index], self.1[index]);
+  }
+
+  #[inline(always)]
+  fn map(self, f: &Fn(f64) -> f64) -> double2x2 {
+    return double2x2(self.0.map_unary(f), self.1.map_unary(f));
+  }
+
+  #[inline(always)]
+  fn transpose(self) -> double2x2 {
+    return double2x2::transpose(self);
+  }
+
+  #[inline(always)]
+  fn to_float(self) -> float2x2 {
+    return float2x2(self.0.to_float(), self.1.to_float());
+  }
+
+  #[inline(always)]
+  fn to_double(self) -> double2x2 {
+    return double2x2(self.0.to_double(), self.1.to_double());
+  }
+}
+
+impl SquareMatrix for double2x2 {
+  #[inline(always)]
+  fn identity() -> double2x2 {
+    return double2x2::identity();
+  }
+
+  #[inline(always)]
+  fn inverse(self) -> double2x2 {
+    return double2x2::inverse(self);
+  }
+
+  #[inline(always)]
+  fn trace(self) -> f64 {
+    return double2x2::trace(self);
+  }
+}
+
 impl double2x2 {
   #[inline(always)]
   pub fn from_columns(c0: double2, c1: double2) -> double2x2 {
@@ -88,11 +346,28 @@ impl double2x2 {
     return double2x2(r0, r1).transpose();
   }
 
+  #[inline(always)]
+  pub fn from_row_major(x: [[f64; 2]; 2]) -> double2x2 {
+    return double2x2::from_rows(double2::from(x[0]), double2::from(x[1]));
+  }
+
+  #[inline(always)]
+  pub fn to_row_major(self) -> [[f64; 2]; 2] {
+    let t = self.transpose();
+
+    return [t.0.into(), t.1.into()];
+  }
+
   #[inline(always)]
   pub fn identity() -> double2x2 {
     return double2x2(double2(1.0, 0.0), double2(0.0, 1.0));
   }
 
+  #[inline(always)]
+  pub fn from_diagonal(d: double2) -> double2x2 {
+    return double2x2(double2(d.0, 0.0), double2(0.0, d.1));
+  }
+
   #[inline(always)]
   pub fn linear_combination(a: f64, x: double2x2, b: f64, y: double2x2) -> double2x2 {
     let a = double2::broadcast(a);
@@ -100,6 +375,11 @@ impl double2x2 {
     return double2x2(a * x.0 + b * y.0, a * x.1 + b * y.1);
   }
 
+  #[inline(always)]
+  pub fn hadamard(self, other: double2x2) -> double2x2 {
+    return double2x2(self.0 * other.0, self.1 * other.1);
+  }
+
   #[inline(always)]
   pub fn transpose(self) -> double2x2 {
     let c0 = double2((self.0).0, (self.1).0);
@@ -108,8 +388,73 @@ impl double2x2 {
     return double2x2(c0, c1);
   }
 
+  #[inline(always)]
+  pub fn diagonal(self) -> double2 {
+    return double2((self.0).0, (self.1).1);
+  }
+
+  #[inline(always)]
+  pub fn trace(self) -> f64 {
+    return (self.0).0 + (self.1).1;
+  }
+
+  #[inline(always)]
+  pub fn to_homogeneous(self) -> double3x3 {
+    return double3x3(double3((self.0).0, (self.0).1, 0.0), double3((self.1).0, (self.1).1, 0.0), double3(0.0, 0.0, 1.0));
+  }
+
+  #[cfg(not(feature = "portable"))]
   #[inline(always)]
   pub fn inverse(self) -> double2x2 {
     return unsafe { __invert_d2(self) };
   }
+
+  #[cfg(feature = "portable")]
+  #[inline(always)]
+  pub fn inverse(self) -> double2x2 {
+    return self.inverse_with_det().0;
+  }
+
+  #[cfg(not(feature = "portable"))]
+  #[inline(always)]
+  pub fn inverse_with_det(self) -> (double2x2, f64) {
+    return (self.inverse(), self.determinant());
+  }
+
+  #[cfg(feature = "portable")]
+  #[inline(always)]
+  pub fn inverse_with_det(self) -> (double2x2, f64) {
+    let det = (self.0).0 * (self.1).1 - (self.0).1 * (self.1).0;
+
+    return (double2x2(double2((self.1).1, -(self.0).1), double2(-(self.1).0, (self.0).0)) * (1.0 / det), det);
+  }
+
+  #[inline(always)]
+  pub fn try_inverse(self) -> Option<double2x2> {
+    return self.try_inverse_epsilon(0.0);
+  }
+
+  #[inline]
+  pub fn try_inverse_epsilon(self, epsilon: f64) -> Option<double2x2> {
+    let (inverse, det) = self.inverse_with_det();
+
+    if (det > epsilon || det < -epsilon) && (inverse.0.is_finite() & inverse.1.is_finite()).all() {
+      return Some(inverse);
+    }
+
+    return None;
+  }
+
+  #[inline]
+  pub fn condition_number_estimate(self) -> f64 {
+    match self.try_inverse() {
+      Some(inverse) => {
+        let a = self.0.abs() + self.1.abs();
+        let b = inverse.0.abs() + inverse.1.abs();
+
+        return a.reduce_max() * b.reduce_max();
+      },
+      None => return std::f64::INFINITY
+    }
+  }
 }
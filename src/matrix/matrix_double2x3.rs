@@ -19,6 +19,24 @@ impl std::ops::Sub for double2x3 {
   }
 }
 
+impl std::ops::Neg for double2x3 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn neg(self) -> Self {
+    return double2x3(-self.0, -self.1);
+  }
+}
+
+impl std::ops::Mul<double2x2> for double2x3 {
+  type Output = double2x3;
+
+  #[inline(always)]
+  fn mul(self, other: double2x2) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<double3x2> for double2x3 {
   type Output = double3x3;
 
@@ -28,6 +46,15 @@ impl std::ops::Mul<double3x2> for double2x3 {
   }
 }
 
+impl std::ops::Mul<double4x2> for double2x3 {
+  type Output = double4x3;
+
+  #[inline(always)]
+  fn mul(self, other: double4x2) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<double2> for double2x3 {
   type Output = double3;
 
@@ -48,6 +75,77 @@ impl std::ops::Mul<f64> for double2x3 {
   }
 }
 
+impl std::ops::Div<f64> for double2x3 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn div(self, other: f64) -> Self {
+    let a = double3::broadcast(other);
+
+    return double2x3(self.0 / a, self.1 / a);
+  }
+}
+
+impl std::ops::Mul<double2x3> for double3 {
+  type Output = double2;
+
+  #[inline(always)]
+  fn mul(self, other: double2x3) -> Self::Output {
+    return other.transpose().dot(self);
+  }
+}
+
+impl std::ops::AddAssign for double2x3 {
+  #[inline(always)]
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl std::ops::SubAssign for double2x3 {
+  #[inline(always)]
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl std::ops::MulAssign<f64> for double2x3 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: f64) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::DivAssign<f64> for double2x3 {
+  #[inline(always)]
+  fn div_assign(&mut self, other: f64) {
+    *self = *self / other;
+  }
+}
+
+impl std::iter::Sum for double2x3 {
+  #[inline]
+  fn sum<I: Iterator<Item=double2x3>>(iter: I) -> double2x3 {
+    return iter.fold(double2x3(double3::from(0), double3::from(0)), |a, b| a + b);
+  }
+}
+
+impl<'a> std::iter::Sum<&'a double2x3> for double2x3 {
+  #[inline]
+  fn sum<I: Iterator<Item=&'a double2x3>>(iter: I) -> double2x3 {
+    return iter.fold(double2x3(double3::from(0), double3::from(0)), |a, b| a + *b);
+  }
+}
+
+impl Dot<double2x2> for double2x3 {
+  type DotProduct = double2x3;
+
+  #[inline(always)]
+  fn dot(self, other: double2x2) -> Self::DotProduct {
+    return double2x3(self.dot(other.0), self.dot(other.1));
+  }
+}
+
 impl Dot<double3x2> for double2x3 {
   type DotProduct = double3x3;
 
@@ -57,6 +155,15 @@ impl Dot<double3x2> for double2x3 {
   }
 }
 
+impl Dot<double4x2> for double2x3 {
+  type DotProduct = double4x3;
+
+  #[inline(always)]
+  fn dot(self, other: double4x2) -> Self::DotProduct {
+    return double4x3(self.dot(other.0), self.dot(other.1), self.dot(other.2), self.dot(other.3));
+  }
+}
+
 impl Dot<double2> for double2x3 {
   type DotProduct = double3;
 
@@ -73,6 +180,109 @@ impl PartialEq for double2x3 {
   }
 }
 
+impl std::ops::Index<usize> for double2x3 {
+  type Output = double3;
+
+  #[inline]
+  fn index(&self, index: usize) -> &double3 {
+    match index {
+      0 => return &self.0,
+      1 => return &self.1,
+      _ => panic!("column index {} out of range for double2x3", index)
+    }
+  }
+}
+
+impl std::ops::IndexMut<usize> for double2x3 {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut double3 {
+    match index {
+      0 => return &mut self.0,
+      1 => return &mut self.1,
+      _ => panic!("column index {} out of range for double2x3", index)
+    }
+  }
+}
+
+impl std::ops::Index<(usize, usize)> for double2x3 {
+  type Output = f64;
+
+  #[inline(always)]
+  fn index(&self, index: (usize, usize)) -> &f64 {
+    return &self[index.1][index.0];
+  }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for double2x3 {
+  #[inline(always)]
+  fn index_mut(&mut self, index: (usize, usize)) -> &mut f64 {
+    return &mut self[index.1][index.0];
+  }
+}
+
+impl From<[[f64; 3]; 2]> for double2x3 {
+  #[inline(always)]
+  fn from(x: [[f64; 3]; 2]) -> double2x3 {
+    return double2x3(double3::from(x[0]), double3::from(x[1]));
+  }
+}
+
+impl From<double2x3> for [[f64; 3]; 2] {
+  #[inline(always)]
+  fn from(x: double2x3) -> [[f64; 3]; 2] {
+    return [x.0.into(), x.1.into()];
+  }
+}
+
+impl From<float2x3> for double2x3 {
+  #[inline(always)]
+  fn from(x: float2x3) -> double2x3 {
+    return x.to_double();
+  }
+}
+
+impl Matrix for double2x3 {
+  type Scalar = f64;
+  type Column = double3;
+  type Row = double2;
+  type Transpose = double3x2;
+  type FloatMatrix = float2x3;
+  type DoubleMatrix = double2x3;
+
+  const ROWS: usize = 3;
+  const COLUMNS: usize = 2;
+
+  #[inline(always)]
+  fn column(self, index: usize) -> double3 {
+    return self[index];
+  }
+
+  #[inline(always)]
+  fn row(self, index: usize) -> double2 {
+    return double2(self.0[index], self.1[index]);
+  }
+
+  #[inline(always)]
+  fn map(self, f: &Fn(f64) -> f64) -> double2x3 {
+    return double2x3(self.0.map_unary(f), self.1.map_unary(f));
+  }
+
+  #[inline(always)]
+  fn transpose(self) -> double3x2 {
+    return double2x3::transpose(self);
+  }
+
+  #[inline(always)]
+  fn to_float(self) -> float2x3 {
+    return float2x3(self.0.to_float(), self.1.to_float());
+  }
+
+  #[inline(always)]
+  fn to_double(self) -> double2x3 {
+    return double2x3(self.0.to_double(), self.1.to_double());
+  }
+}
+
 impl double2x3 {
   #[inline(always)]
   pub fn from_columns(c0: double3, c1: double3) -> double2x3 {
@@ -84,6 +294,18 @@ impl double2x3 {
     return double3x2(r0, r1, r2).transpose();
   }
 
+  #[inline(always)]
+  pub fn from_row_major(x: [[f64; 2]; 3]) -> double2x3 {
+    return double2x3::from_rows(double2::from(x[0]), double2::from(x[1]), double2::from(x[2]));
+  }
+
+  #[inline(always)]
+  pub fn to_row_major(self) -> [[f64; 2]; 3] {
+    let t = self.transpose();
+
+    return [t.0.into(), t.1.into(), t.2.into()];
+  }
+
   #[inline(always)]
   pub fn linear_combination(a: f64, x: double2x3, b: f64, y: double2x3) -> double2x3 {
     let a = double3::broadcast(a);
@@ -91,6 +313,11 @@ impl double2x3 {
     return double2x3(a * x.0 + b * y.0, a * x.1 + b * y.1);
   }
 
+  #[inline(always)]
+  pub fn hadamard(self, other: double2x3) -> double2x3 {
+    return double2x3(self.0 * other.0, self.1 * other.1);
+  }
+
   #[inline(always)]
   pub fn transpose(self) -> double3x2 {
     let c0 = double2((self.0).0, (self.1).0);
@@ -99,4 +326,9 @@ impl double2x3 {
 
     return double3x2(c0, c1, c2);
   }
+
+  #[inline(always)]
+  pub fn upper_left_2x2(self) -> double2x2 {
+    return double2x2(double2((self.0).0, (self.0).1), double2((self.1).0, (self.1).1));
+  }
 }
@@ -19,6 +19,15 @@ impl std::ops::Sub for double3x2 {
   }
 }
 
+impl std::ops::Neg for double3x2 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn neg(self) -> Self {
+    return double3x2(-self.0, -self.1, -self.2);
+  }
+}
+
 impl std::ops::Mul<double2x3> for double3x2 {
   type Output = double2x2;
 
@@ -28,6 +37,24 @@ impl std::ops::Mul<double2x3> for double3x2 {
   }
 }
 
+impl std::ops::Mul<double3x3> for double3x2 {
+  type Output = double3x2;
+
+  #[inline(always)]
+  fn mul(self, other: double3x3) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
+impl std::ops::Mul<double4x3> for double3x2 {
+  type Output = double4x2;
+
+  #[inline(always)]
+  fn mul(self, other: double4x3) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<double3> for double3x2 {
   type Output = double2;
 
@@ -48,6 +75,68 @@ impl std::ops::Mul<f64> for double3x2 {
   }
 }
 
+impl std::ops::Div<f64> for double3x2 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn div(self, other: f64) -> Self {
+    let a = double2::broadcast(other);
+
+    return double3x2(self.0 / a, self.1 / a, self.2 / a);
+  }
+}
+
+impl std::ops::Mul<double3x2> for double2 {
+  type Output = double3;
+
+  #[inline(always)]
+  fn mul(self, other: double3x2) -> Self::Output {
+    return other.transpose().dot(self);
+  }
+}
+
+impl std::ops::AddAssign for double3x2 {
+  #[inline(always)]
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl std::ops::SubAssign for double3x2 {
+  #[inline(always)]
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl std::ops::MulAssign<f64> for double3x2 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: f64) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::DivAssign<f64> for double3x2 {
+  #[inline(always)]
+  fn div_assign(&mut self, other: f64) {
+    *self = *self / other;
+  }
+}
+
+impl std::iter::Sum for double3x2 {
+  #[inline]
+  fn sum<I: Iterator<Item=double3x2>>(iter: I) -> double3x2 {
+    return iter.fold(double3x2(double2::from(0), double2::from(0), double2::from(0)), |a, b| a + b);
+  }
+}
+
+impl<'a> std::iter::Sum<&'a double3x2> for double3x2 {
+  #[inline]
+  fn sum<I: Iterator<Item=&'a double3x2>>(iter: I) -> double3x2 {
+    return iter.fold(double3x2(double2::from(0), double2::from(0), double2::from(0)), |a, b| a + *b);
+  }
+}
+
 impl Dot<double2x3> for double3x2 {
   type DotProduct = double2x2;
 
@@ -57,6 +146,24 @@ impl Dot<double2x3> for double3x2 {
   }
 }
 
+impl Dot<double3x3> for double3x2 {
+  type DotProduct = double3x2;
+
+  #[inline(always)]
+  fn dot(self, other: double3x3) -> Self::DotProduct {
+    return double3x2(self.dot(other.0), self.dot(other.1), self.dot(other.2));
+  }
+}
+
+impl Dot<double4x3> for double3x2 {
+  type DotProduct = double4x2;
+
+  #[inline(always)]
+  fn dot(self, other: double4x3) -> Self::DotProduct {
+    return double4x2(self.dot(other.0), self.dot(other.1), self.dot(other.2), self.dot(other.3));
+  }
+}
+
 impl Dot<double3> for double3x2 {
   type DotProduct = double2;
 
@@ -73,6 +180,111 @@ impl PartialEq for double3x2 {
   }
 }
 
+impl std::ops::Index<usize> for double3x2 {
+  type Output = double2;
+
+  #[inline]
+  fn index(&self, index: usize) -> &double2 {
+    match index {
+      0 => return &self.0,
+      1 => return &self.1,
+      2 => return &self.2,
+      _ => panic!("column index {} out of range for double3x2", index)
+    }
+  }
+}
+
+impl std::ops::IndexMut<usize> for double3x2 {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut double2 {
+    match index {
+      0 => return &mut self.0,
+      1 => return &mut self.1,
+      2 => return &mut self.2,
+      _ => panic!("column index {} out of range for double3x2", index)
+    }
+  }
+}
+
+impl std::ops::Index<(usize, usize)> for double3x2 {
+  type Output = f64;
+
+  #[inline(always)]
+  fn index(&self, index: (usize, usize)) -> &f64 {
+    return &self[index.1][index.0];
+  }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for double3x2 {
+  #[inline(always)]
+  fn index_mut(&mut self, index: (usize, usize)) -> &mut f64 {
+    return &mut self[index.1][index.0];
+  }
+}
+
+impl From<[[f64; 2]; 3]> for double3x2 {
+  #[inline(always)]
+  fn from(x: [[f64; 2]; 3]) -> double3x2 {
+    return double3x2(double2::from(x[0]), double2::from(x[1]), double2::from(x[2]));
+  }
+}
+
+impl From<double3x2> for [[f64; 2]; 3] {
+  #[inline(always)]
+  fn from(x: double3x2) -> [[f64; 2]; 3] {
+    return [x.0.into(), x.1.into(), x.2.into()];
+  }
+}
+
+impl From<float3x2> for double3x2 {
+  #[inline(always)]
+  fn from(x: float3x2) -> double3x2 {
+    return x.to_double();
+  }
+}
+
+impl Matrix for double3x2 {
+  type Scalar = f64;
+  type Column = double2;
+  type Row = double3;
+  type Transpose = double2x3;
+  type FloatMatrix = float3x2;
+  type DoubleMatrix = double3x2;
+
+  const ROWS: usize = 2;
+  const COLUMNS: usize = 3;
+
+  #[inline(always)]
+  fn column(self, index: usize) -> double2 {
+    return self[index];
+  }
+
+  #[inline(always)]
+  fn row(self, index: usize) -> double3 {
+    return double3(self.0[index], self.1[index], self.2[index]);
+  }
+
+  #[inline(always)]
+  fn map(self, f: &Fn(f64) -> f64) -> double3x2 {
+    return double3x2(self.0.map_unary(f), self.1.map_unary(f), self.2.map_unary(f));
+  }
+
+  #[inline(always)]
+  fn transpose(self) -> double2x3 {
+    return double3x2::transpose(self);
+  }
+
+  #[inline(always)]
+  fn to_float(self) -> float3x2 {
+    return float3x2(self.0.to_float(), self.1.to_float(), self.2.to_float());
+  }
+
+  #[inline(always)]
+  fn to_double(self) -> double3x2 {
+    return double3x2(self.0.to_double(), self.1.to_double(), self.2.to_double());
+  }
+}
+
 impl double3x2 {
   #[inline(always)]
   pub fn from_columns(c0: double2, c1: double2, c2: double2) -> double3x2 {
@@ -84,6 +296,18 @@ impl double3x2 {
     return double2x3(r0, r1).transpose();
   }
 
+  #[inline(always)]
+  pub fn from_row_major(x: [[f64; 3]; 2]) -> double3x2 {
+    return double3x2::from_rows(double3::from(x[0]), double3::from(x[1]));
+  }
+
+  #[inline(always)]
+  pub fn to_row_major(self) -> [[f64; 3]; 2] {
+    let t = self.transpose();
+
+    return [t.0.into(), t.1.into()];
+  }
+
   #[inline(always)]
   pub fn linear_combination(a: f64, x: double3x2, b: f64, y: double3x2) -> double3x2 {
     let a = double2::broadcast(a);
@@ -91,6 +315,11 @@ impl double3x2 {
     return double3x2(a * x.0 + b * y.0, a * x.1 + b * y.1, a * x.2 + b * y.2);
   }
 
+  #[inline(always)]
+  pub fn hadamard(self, other: double3x2) -> double3x2 {
+    return double3x2(self.0 * other.0, self.1 * other.1, self.2 * other.2);
+  }
+
   #[inline(always)]
   pub fn transpose(self) -> double2x3 {
     let c0 = double3((self.0).0, (self.1).0, (self.2).0);
@@ -98,4 +327,14 @@ impl double3x2 {
 
     return double2x3(c0, c1);
   }
+
+  #[inline(always)]
+  pub fn upper_left_2x2(self) -> double2x2 {
+    return double2x2(self.0, self.1);
+  }
+
+  #[inline(always)]
+  pub fn to_homogeneous(self) -> double3x3 {
+    return double3x3(double3((self.0).0, (self.0).1, 0.0), double3((self.1).0, (self.1).1, 0.0), double3((self.2).0, (self.2).1, 1.0));
+  }
 }
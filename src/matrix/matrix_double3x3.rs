@@ -1,6 +1,7 @@
 use std;
 use ::*;
 
+#[cfg(not(feature = "portable"))]
 extern {
   fn __invert_d3(a: double3x3) -> double3x3;
 }
@@ -23,6 +24,24 @@ impl std::ops::Sub for double3x3 {
   }
 }
 
+impl std::ops::Neg for double3x3 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn neg(self) -> Self {
+    return double3x3(-self.0, -self.1, -self.2);
+  }
+}
+
+impl std::ops::Mul<double2x3> for double3x3 {
+  type Output = double2x3;
+
+  #[inline(always)]
+  fn mul(self, other: double2x3) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<double3x3> for double3x3 {
   type Output = double3x3;
 
@@ -32,6 +51,15 @@ impl std::ops::Mul<double3x3> for double3x3 {
   }
 }
 
+impl std::ops::Mul<double4x3> for double3x3 {
+  type Output = double4x3;
+
+  #[inline(always)]
+  fn mul(self, other: double4x3) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<double3> for double3x3 {
   type Output = double3;
 
@@ -52,6 +80,98 @@ impl std::ops::Mul<f64> for double3x3 {
   }
 }
 
+impl std::ops::Div<f64> for double3x3 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn div(self, other: f64) -> Self {
+    let a = double3::broadcast(other);
+
+    return double3x3(self.0 / a, self.1 / a, self.2 / a);
+  }
+}
+
+impl std::ops::Mul<double3x3> for double3 {
+  type Output = double3;
+
+  #[inline(always)]
+  fn mul(self, other: double3x3) -> Self::Output {
+    return other.transpose().dot(self);
+  }
+}
+
+impl std::ops::AddAssign for double3x3 {
+  #[inline(always)]
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl std::ops::SubAssign for double3x3 {
+  #[inline(always)]
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl std::ops::MulAssign for double3x3 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: Self) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::MulAssign<f64> for double3x3 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: f64) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::DivAssign<f64> for double3x3 {
+  #[inline(always)]
+  fn div_assign(&mut self, other: f64) {
+    *self = *self / other;
+  }
+}
+
+impl std::iter::Sum for double3x3 {
+  #[inline]
+  fn sum<I: Iterator<Item=double3x3>>(iter: I) -> double3x3 {
+    return iter.fold(double3x3(double3::from(0), double3::from(0), double3::from(0)), |a, b| a + b);
+  }
+}
+
+impl<'a> std::iter::Sum<&'a double3x3> for double3x3 {
+  #[inline]
+  fn sum<I: Iterator<Item=&'a double3x3>>(iter: I) -> double3x3 {
+    return iter.fold(double3x3(double3::from(0), double3::from(0), double3::from(0)), |a, b| a + *b);
+  }
+}
+
+impl std::iter::Product for double3x3 {
+  #[inline]
+  fn product<I: Iterator<Item=double3x3>>(iter: I) -> double3x3 {
+    return iter.fold(double3x3::identity(), |a, b| a * b);
+  }
+}
+
+impl<'a> std::iter::Product<&'a double3x3> for double3x3 {
+  #[inline]
+  fn product<I: Iterator<Item=&'a double3x3>>(iter: I) -> double3x3 {
+    return iter.fold(double3x3::identity(), |a, b| a * *b);
+  }
+}
+
+impl Dot<double2x3> for double3x3 {
+  type DotProduct = double2x3;
+
+  #[inline(always)]
+  fn dot(self, other: double2x3) -> Self::DotProduct {
+    return double2x3(self.dot(other.0), self.dot(other.1));
+  }
+}
+
 impl Dot<double3x3> for double3x3 {
   type DotProduct = double3x3;
 
@@ -61,6 +181,15 @@ impl Dot<double3x3> for double3x3 {
   }
 }
 
+impl Dot<double4x3> for double3x3 {
+  type DotProduct = double4x3;
+
+  #[inline(always)]
+  fn dot(self, other: double4x3) -> Self::DotProduct {
+    return double4x3(self.dot(other.0), self.dot(other.1), self.dot(other.2), self.dot(other.3));
+  }
+}
+
 impl Dot<double3> for double3x3 {
   type DotProduct = double3;
 
@@ -77,6 +206,137 @@ impl PartialEq for double3x3 {
   }
 }
 
+impl std::ops::Index<usize> for double3x3 {
+  type Output = double3;
+
+  #[inline]
+  fn index(&self, index: usize) -> &double3 {
+    match index {
+      0 => return &self.0,
+      1 => return &self.1,
+      2 => return &self.2,
+      _ => panic!("column index {} out of range for double3x3", index)
+    }
+  }
+}
+
+impl std::ops::IndexMut<usize> for double3x3 {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut double3 {
+    match index {
+      0 => return &mut self.0,
+      1 => return &mut self.1,
+      2 => return &mut self.2,
+      _ => panic!("column index {} out of range for double3x3", index)
+    }
+  }
+}
+
+impl std::ops::Index<(usize, usize)> for double3x3 {
+  type Output = f64;
+
+  #[inline(always)]
+  fn index(&self, index: (usize, usize)) -> &f64 {
+    return &self[index.1][index.0];
+  }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for double3x3 {
+  #[inline(always)]
+  fn index_mut(&mut self, index: (usize, usize)) -> &mut f64 {
+    return &mut self[index.1][index.0];
+  }
+}
+
+impl From<[[f64; 3]; 3]> for double3x3 {
+  #[inline(always)]
+  fn from(x: [[f64; 3]; 3]) -> double3x3 {
+    return double3x3(double3::from(x[0]), double3::from(x[1]), double3::from(x[2]));
+  }
+}
+
+impl From<double3x3> for [[f64; 3]; 3] {
+  #[inline(always)]
+  fn from(x: double3x3) -> [[f64; 3]; 3] {
+    return [x.0.into(), x.1.into(), x.2.into()];
+  }
+}
+
+impl From<float3x3> for double3x3 {
+  #[inline(always)]
+  fn from(x: float3x3) -> double3x3 {
+    return x.to_double();
+  }
+}
+
+impl Determinant for double3x3 {
+  type Scalar = f64;
+
+  #[inline(always)]
+  fn determinant(self) -> f64 {
+    return dot(self.0, cross(self.1, self.2));
+  }
+}
+
+impl Matrix for double3x3 {
+  type Scalar = f64;
+  type Column = double3;
+  type Row = double3;
+  type Transpose = double3x3;
+  type FloatMatrix = float3x3;
+  type DoubleMatrix = double3x3;
+
+  const ROWS: usize = 3;
+  const COLUMNS: usize = 3;
+
+  #[inline(always)]
+  fn column(self, index: usize) -> double3 {
+    return self[index];
+  }
+
+  #[inline(always)]
+  fn row(self, index: usize) -> double3 {
+    return double3(self.0[index], self.1[index], self.2[index]);
+  }
+
+  #[inline(always)]
+  fn map(self, f: &Fn(f64) -> f64) -> double3x3 {
+    return double3x3(self.0.map_unary(f), self.1.map_unary(f), self.2.map_unary(f));
+  }
+
+  #[inline(always)]
+  fn transpose(self) -> double3x3 {
+    return double3x3::transpose(self);
+  }
+
+  #[inline(always)]
+  fn to_float(self) -> float3x3 {
+    return float3x3(self.0.to_float(), self.1.to_float(), self.2.to_float());
+  }
+
+  #[inline(always)]
+  fn to_double(self) -> double3x3 {
+    return double3x3(self.0.to_double(), self.1.to_double(), self.2.to_double());
+  }
+}
+
+impl SquareMatrix for double3x3 {
+  #[inline(always)]
+  fn identity() -> double3x3 {
+    return double3x3::identity();
+  }
+
+  #[inline(always)]
+  fn inverse(self) -> double3x3 {
+    return double3x3::inverse(self);
+  }
+
+  #[inline(always)]
+  fn trace(self) -> f64 {
+    return double3x3::trace(self);
+  }
+}
+
 impl double3x3 {
   #[inline(always)]
   pub fn from_columns(c0: double3, c1: double3, c2: double3) -> double3x3 {
@@ -88,11 +348,28 @@ impl double3x3 {
     return double3x3(r0, r1, r2).transpose();
   }
 
+  #[inline(always)]
+  pub fn from_row_major(x: [[f64; 3]; 3]) -> double3x3 {
+    return double3x3::from_rows(double3::from(x[0]), double3::from(x[1]), double3::from(x[2]));
+  }
+
+  #[inline(always)]
+  pub fn to_row_major(self) -> [[f64; 3]; 3] {
+    let t = self.transpose();
+
+    return [t.0.into(), t.1.into(), t.2.into()];
+  }
+
   #[inline(always)]
   pub fn identity() -> double3x3 {
     return double3x3(double3(1.0, 0.0, 0.0), double3(0.0, 1.0, 0.0), double3(0.0, 0.0, 1.0));
   }
 
+  #[inline(always)]
+  pub fn from_diagonal(d: double3) -> double3x3 {
+    return double3x3(double3(d.0, 0.0, 0.0), double3(0.0, d.1, 0.0), double3(0.0, 0.0, d.2));
+  }
+
   #[inline(always)]
   pub fn linear_combination(a: f64, x: double3x3, b: f64, y: double3x3) -> double3x3 {
     let a = double3::broadcast(a);
@@ -100,6 +377,11 @@ impl double3x3 {
     return double3x3(a * x.0 + b * y.0, a * x.1 + b * y.1, a * x.2 + b * y.2);
   }
 
+  #[inline(always)]
+  pub fn hadamard(self, other: double3x3) -> double3x3 {
+    return double3x3(self.0 * other.0, self.1 * other.1, self.2 * other.2);
+  }
+
   #[inline(always)]
   pub fn transpose(self) -> double3x3 {
     let c0 = double3((self.0).0, (self.1).0, (self.2).0);
@@ -109,8 +391,102 @@ impl double3x3 {
     return double3x3(c0, c1, c2);
   }
 
+  #[inline(always)]
+  pub fn diagonal(self) -> double3 {
+    return double3((self.0).0, (self.1).1, (self.2).2);
+  }
+
+  #[inline(always)]
+  pub fn trace(self) -> f64 {
+    return (self.0).0 + (self.1).1 + (self.2).2;
+  }
+
+  #[inline(always)]
+  pub fn upper_left_2x2(self) -> double2x2 {
+    return double2x2(double2((self.0).0, (self.0).1), double2((self.1).0, (self.1).1));
+  }
+
+  #[inline(always)]
+  pub fn to_homogeneous(self) -> double4x4 {
+    return double4x4(double4((self.0).0, (self.0).1, (self.0).2, 0.0), double4((self.1).0, (self.1).1, (self.1).2, 0.0), double4((self.2).0, (self.2).1, (self.2).2, 0.0), double4(0.0, 0.0, 0.0, 1.0));
+  }
+
+  #[inline(always)]
+  pub fn to_affine(self) -> double3x2 {
+    return double3x2(double2((self.0).0, (self.0).1), double2((self.1).0, (self.1).1), double2((self.2).0, (self.2).1));
+  }
+
+  #[inline]
+  pub fn minor(self, row: usize, column: usize) -> double2x2 {
+    assert!(row < 3 && column < 3, "minor ({}, {}) out of range for double3x3", row, column);
+
+    let mut m = double2x2::default();
+
+    for c in 0 .. 2 {
+      for r in 0 .. 2 {
+        m[(r, c)] = self[(r + (r >= row) as usize, c + (c >= column) as usize)];
+      }
+    }
+
+    return m;
+  }
+
+  #[cfg(not(feature = "portable"))]
   #[inline(always)]
   pub fn inverse(self) -> double3x3 {
     return unsafe { __invert_d3(self) };
   }
+
+  #[cfg(feature = "portable")]
+  #[inline(always)]
+  pub fn inverse(self) -> double3x3 {
+    return self.inverse_with_det().0;
+  }
+
+  #[cfg(not(feature = "portable"))]
+  #[inline(always)]
+  pub fn inverse_with_det(self) -> (double3x3, f64) {
+    return (self.inverse(), self.determinant());
+  }
+
+  #[cfg(feature = "portable")]
+  #[inline(always)]
+  pub fn inverse_with_det(self) -> (double3x3, f64) {
+    let r0 = cross(self.1, self.2);
+    let r1 = cross(self.2, self.0);
+    let r2 = cross(self.0, self.1);
+
+    let det = dot(self.0, r0);
+
+    return (double3x3::from_rows(r0, r1, r2) * (1.0 / det), det);
+  }
+
+  #[inline(always)]
+  pub fn try_inverse(self) -> Option<double3x3> {
+    return self.try_inverse_epsilon(0.0);
+  }
+
+  #[inline]
+  pub fn try_inverse_epsilon(self, epsilon: f64) -> Option<double3x3> {
+    let (inverse, det) = self.inverse_with_det();
+
+    if (det > epsilon || det < -epsilon) && (inverse.0.is_finite() & inverse.1.is_finite() & inverse.2.is_finite()).all() {
+      return Some(inverse);
+    }
+
+    return None;
+  }
+
+  #[inline]
+  pub fn condition_number_estimate(self) -> f64 {
+    match self.try_inverse() {
+      Some(inverse) => {
+        let a = self.0.abs() + self.1.abs() + self.2.abs();
+        let b = inverse.0.abs() + inverse.1.abs() + inverse.2.abs();
+
+        return a.reduce_max() * b.reduce_max();
+      },
+      None => return std::f64::INFINITY
+    }
+  }
 }
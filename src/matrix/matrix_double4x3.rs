@@ -19,6 +19,24 @@ impl std::ops::Sub for double4x3 {
   }
 }
 
+impl std::ops::Neg for double4x3 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn neg(self) -> Self {
+    return double4x3(-self.0, -self.1, -self.2, -self.3);
+  }
+}
+
+impl std::ops::Mul<double2x4> for double4x3 {
+  type Output = double2x3;
+
+  #[inline(always)]
+  fn mul(self, other: double2x4) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<double3x4> for double4x3 {
   type Output = double3x3;
 
@@ -28,6 +46,15 @@ impl std::ops::Mul<double3x4> for double4x3 {
   }
 }
 
+impl std::ops::Mul<double4x4> for double4x3 {
+  type Output = double4x3;
+
+  #[inline(always)]
+  fn mul(self, other: double4x4) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<double4> for double4x3 {
   type Output = double3;
 
@@ -48,6 +75,77 @@ impl std::ops::Mul<f64> for double4x3 {
   }
 }
 
+impl std::ops::Div<f64> for double4x3 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn div(self, other: f64) -> Self {
+    let a = double3::broadcast(other);
+
+    return double4x3(self.0 / a, self.1 / a, self.2 / a, self.3 / a);
+  }
+}
+
+impl std::ops::Mul<double4x3> for double3 {
+  type Output = double4;
+
+  #[inline(always)]
+  fn mul(self, other: double4x3) -> Self::Output {
+    return other.transpose().dot(self);
+  }
+}
+
+impl std::ops::AddAssign for double4x3 {
+  #[inline(always)]
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl std::ops::SubAssign for double4x3 {
+  #[inline(always)]
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl std::ops::MulAssign<f64> for double4x3 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: f64) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::DivAssign<f64> for double4x3 {
+  #[inline(always)]
+  fn div_assign(&mut self, other: f64) {
+    *self = *self / other;
+  }
+}
+
+impl std::iter::Sum for double4x3 {
+  #[inline]
+  fn sum<I: Iterator<Item=double4x3>>(iter: I) -> double4x3 {
+    return iter.fold(double4x3(double3::from(0), double3::from(0), double3::from(0), double3::from(0)), |a, b| a + b);
+  }
+}
+
+impl<'a> std::iter::Sum<&'a double4x3> for double4x3 {
+  #[inline]
+  fn sum<I: Iterator<Item=&'a double4x3>>(iter: I) -> double4x3 {
+    return iter.fold(double4x3(double3::from(0), double3::from(0), double3::from(0), double3::from(0)), |a, b| a + *b);
+  }
+}
+
+impl Dot<double2x4> for double4x3 {
+  type DotProduct = double2x3;
+
+  #[inline(always)]
+  fn dot(self, other: double2x4) -> Self::DotProduct {
+    return double2x3(self.dot(other.0), self.dot(other.1));
+  }
+}
+
 impl Dot<double3x4> for double4x3 {
   type DotProduct = double3x3;
 
@@ -57,6 +155,15 @@ impl Dot<double3x4> for double4x3 {
   }
 }
 
+impl Dot<double4x4> for double4x3 {
+  type DotProduct = double4x3;
+
+  #[inline(always)]
+  fn dot(self, other: double4x4) -> Self::DotProduct {
+    return double4x3(self.dot(other.0), self.dot(other.1), self.dot(other.2), self.dot(other.3));
+  }
+}
+
 impl Dot<double4> for double4x3 {
   type DotProduct = double3;
 
@@ -73,6 +180,113 @@ impl PartialEq for double4x3 {
   }
 }
 
+impl std::ops::Index<usize> for double4x3 {
+  type Output = double3;
+
+  #[inline]
+  fn index(&self, index: usize) -> &double3 {
+    match index {
+      0 => return &self.0,
+      1 => return &self.1,
+      2 => return &self.2,
+      3 => return &self.3,
+      _ => panic!("column index {} out of range for double4x3", index)
+    }
+  }
+}
+
+impl std::ops::IndexMut<usize> for double4x3 {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut double3 {
+    match index {
+      0 => return &mut self.0,
+      1 => return &mut self.1,
+      2 => return &mut self.2,
+      3 => return &mut self.3,
+      _ => panic!("column index {} out of range for double4x3", index)
+    }
+  }
+}
+
+impl std::ops::Index<(usize, usize)> for double4x3 {
+  type Output = f64;
+
+  #[inline(always)]
+  fn index(&self, index: (usize, usize)) -> &f64 {
+    return &self[index.1][index.0];
+  }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for double4x3 {
+  #[inline(always)]
+  fn index_mut(&mut self, index: (usize, usize)) -> &mut f64 {
+    return &mut self[index.1][index.0];
+  }
+}
+
+impl From<[[f64; 3]; 4]> for double4x3 {
+  #[inline(always)]
+  fn from(x: [[f64; 3]; 4]) -> double4x3 {
+    return double4x3(double3::from(x[0]), double3::from(x[1]), double3::from(x[2]), double3::from(x[3]));
+  }
+}
+
+impl From<double4x3> for [[f64; 3]; 4] {
+  #[inline(always)]
+  fn from(x: double4x3) -> [[f64; 3]; 4] {
+    return [x.0.into(), x.1.into(), x.2.into(), x.3.into()];
+  }
+}
+
+impl From<float4x3> for double4x3 {
+  #[inline(always)]
+  fn from(x: float4x3) -> double4x3 {
+    return x.to_double();
+  }
+}
+
+impl Matrix for double4x3 {
+  type Scalar = f64;
+  type Column = double3;
+  type Row = double4;
+  type Transpose = double3x4;
+  type FloatMatrix = float4x3;
+  type DoubleMatrix = double4x3;
+
+  const ROWS: usize = 3;
+  const COLUMNS: usize = 4;
+
+  #[inline(always)]
+  fn column(self, index: usize) -> double3 {
+    return self[index];
+  }
+
+  #[inline(always)]
+  fn row(self, index: usize) -> double4 {
+    return double4(self.0[index], self.1[index], self.2[index], self.3[index]);
+  }
+
+  #[inline(always)]
+  fn map(self, f: &Fn(f64) -> f64) -> double4x3 {
+    return double4x3(self.0.map_unary(f), self.1.map_unary(f), self.2.map_unary(f), self.3.map_unary(f));
+  }
+
+  #[inline(always)]
+  fn transpose(self) -> double3x4 {
+    return double4x3::transpose(self);
+  }
+
+  #[inline(always)]
+  fn to_float(self) -> float4x3 {
+    return float4x3(self.0.to_float(), self.1.to_float(), self.2.to_float(), self.3.to_float());
+  }
+
+  #[inline(always)]
+  fn to_double(self) -> double4x3 {
+    return double4x3(self.0.to_double(), self.1.to_double(), self.2.to_double(), self.3.to_double());
+  }
+}
+
 impl double4x3 {
   #[inline(always)]
   pub fn from_columns(c0: double3, c1: double3, c2: double3, c3: double3) -> double4x3 {
@@ -84,6 +298,18 @@ impl double4x3 {
     return double3x4(r0, r1, r2).transpose();
   }
 
+  #[inline(always)]
+  pub fn from_row_major(x: [[f64; 4]; 3]) -> double4x3 {
+    return double4x3::from_rows(double4::from(x[0]), double4::from(x[1]), double4::from(x[2]));
+  }
+
+  #[inline(always)]
+  pub fn to_row_major(self) -> [[f64; 4]; 3] {
+    let t = self.transpose();
+
+    return [t.0.into(), t.1.into(), t.2.into()];
+  }
+
   #[inline(always)]
   pub fn linear_combination(a: f64, x: double4x3, b: f64, y: double4x3) -> double4x3 {
     let a = double3::broadcast(a);
@@ -91,6 +317,11 @@ impl double4x3 {
     return double4x3(a * x.0 + b * y.0, a * x.1 + b * y.1, a * x.2 + b * y.2, a * x.3 + b * y.3);
   }
 
+  #[inline(always)]
+  pub fn hadamard(self, other: double4x3) -> double4x3 {
+    return double4x3(self.0 * other.0, self.1 * other.1, self.2 * other.2, self.3 * other.3);
+  }
+
   #[inline(always)]
   pub fn transpose(self) -> double3x4 {
     let c0 = double4((self.0).0, (self.1).0, (self.2).0, (self.3).0);
@@ -99,4 +330,40 @@ impl double4x3 {
 
     return double3x4(c0, c1, c2);
   }
+
+  #[inline(always)]
+  pub fn upper_left_2x2(self) -> double2x2 {
+    return double2x2(double2((self.0).0, (self.0).1), double2((self.1).0, (self.1).1));
+  }
+
+  #[inline(always)]
+  pub fn upper_left_3x3(self) -> double3x3 {
+    return double3x3(self.0, self.1, self.2);
+  }
+
+  #[inline(always)]
+  pub fn to_homogeneous(self) -> double4x4 {
+    return double4x4(double4((self.0).0, (self.0).1, (self.0).2, 0.0), double4((self.1).0, (self.1).1, (self.1).2, 0.0), double4((self.2).0, (self.2).1, (self.2).2, 0.0), double4((self.3).0, (self.3).1, (self.3).2, 1.0));
+  }
+
+  #[inline(always)]
+  pub fn inverse_affine(self) -> double4x3 {
+    let m = double3x3(self.0, self.1, self.2).inverse();
+    let t = -(m * self.3);
+
+    return double4x3(m.0, m.1, m.2, t);
+  }
+
+  #[inline(always)]
+  pub fn inverse_rigid(self) -> double4x3 {
+    let m = double3x3(self.0, self.1, self.2).transpose();
+    let t = -(m * self.3);
+
+    return double4x3(m.0, m.1, m.2, t);
+  }
+
+  #[inline(always)]
+  pub fn normal_matrix(self) -> double3x3 {
+    return double3x3(self.0, self.1, self.2).inverse().transpose();
+  }
 }
@@ -1,6 +1,7 @@
 use std;
 use ::*;
 
+#[cfg(not(feature = "portable"))]
 extern {
   fn __invert_d4(a: double4x4) -> double4x4;
 }
@@ -23,6 +24,33 @@ impl std::ops::Sub for double4x4 {
   }
 }
 
+impl std::ops::Neg for double4x4 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn neg(self) -> Self {
+    return double4x4(-self.0, -self.1, -self.2, -self.3);
+  }
+}
+
+impl std::ops::Mul<double2x4> for double4x4 {
+  type Output = double2x4;
+
+  #[inline(always)]
+  fn mul(self, other: double2x4) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
+impl std::ops::Mul<double3x4> for double4x4 {
+  type Output = double3x4;
+
+  #[inline(always)]
+  fn mul(self, other: double3x4) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<double4x4> for double4x4 {
   type Output = double4x4;
 
@@ -52,6 +80,107 @@ impl std::ops::Mul<f64> for double4x4 {
   }
 }
 
+impl std::ops::Div<f64> for double4x4 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn div(self, other: f64) -> Self {
+    let a = double4::broadcast(other);
+
+    return double4x4(self.0 / a, self.1 / a, self.2 / a, self.3 / a);
+  }
+}
+
+impl std::ops::Mul<double4x4> for double4 {
+  type Output = double4;
+
+  #[inline(always)]
+  fn mul(self, other: double4x4) -> Self::Output {
+    return other.transpose().dot(self);
+  }
+}
+
+impl std::ops::AddAssign for double4x4 {
+  #[inline(always)]
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl std::ops::SubAssign for double4x4 {
+  #[inline(always)]
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl std::ops::MulAssign for double4x4 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: Self) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::MulAssign<f64> for double4x4 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: f64) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::DivAssign<f64> for double4x4 {
+  #[inline(always)]
+  fn div_assign(&mut self, other: f64) {
+    *self = *self / other;
+  }
+}
+
+impl std::iter::Sum for double4x4 {
+  #[inline]
+  fn sum<I: Iterator<Item=double4x4>>(iter: I) -> double4x4 {
+    return iter.fold(double4x4(double4::from(0), double4::from(0), double4::from(0), double4::from(0)), |a, b| a + b);
+  }
+}
+
+impl<'a> std::iter::Sum<&'a double4x4> for double4x4 {
+  #[inline]
+  fn sum<I: Iterator<Item=&'a double4x4>>(iter: I) -> double4x4 {
+    return iter.fold(double4x4(double4::from(0), double4::from(0), double4::from(0), double4::from(0)), |a, b| a + *b);
+  }
+}
+
+impl std::iter::Product for double4x4 {
+  #[inline]
+  fn product<I: Iterator<Item=double4x4>>(iter: I) -> double4x4 {
+    return iter.fold(double4x4::identity(), |a, b| a * b);
+  }
+}
+
+impl<'a> std::iter::Product<&'a double4x4> for double4x4 {
+  #[inline]
+  fn product<I: Iterator<Item=&'a double4x4>>(iter: I) -> double4x4 {
+    return iter.fold(double4x4::identity(), |a, b| a * *b);
+  }
+}
+
+impl Dot<double2x4> for double4x4 {
+  type DotProduct = double2x4;
+
+  #[inline(always)]
+  fn dot(self, other: double2x4) -> Self::DotProduct {
+    return double2x4(self.dot(other.0), self.dot(other.1));
+  }
+}
+
+impl Dot<double3x4> for double4x4 {
+  type DotProduct = double3x4;
+
+  #[inline(always)]
+  fn dot(self, other: double3x4) -> Self::DotProduct {
+    return double3x4(self.dot(other.0), self.dot(other.1), self.dot(other.2));
+  }
+}
+
 impl Dot<double4x4> for double4x4 {
   type DotProduct = double4x4;
 
@@ -77,6 +206,147 @@ impl PartialEq for double4x4 {
   }
 }
 
+impl std::ops::Index<usize> for double4x4 {
+  type Output = double4;
+
+  #[inline]
+  fn index(&self, index: usize) -> &double4 {
+    match index {
+      0 => return &self.0,
+      1 => return &self.1,
+      2 => return &self.2,
+      3 => return &self.3,
+      _ => panic!("column index {} out of range for double4x4", index)
+    }
+  }
+}
+
+impl std::ops::IndexMut<usize> for double4x4 {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut double4 {
+    match index {
+      0 => return &mut self.0,
+      1 => return &mut self.1,
+      2 => return &mut self.2,
+      3 => return &mut self.3,
+      _ => panic!("column index {} out of range for double4x4", index)
+    }
+  }
+}
+
+impl std::ops::Index<(usize, usize)> for double4x4 {
+  type Output = f64;
+
+  #[inline(always)]
+  fn index(&self, index: (usize, usize)) -> &f64 {
+    return &self[index.1][index.0];
+  }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for double4x4 {
+  #[inline(always)]
+  fn index_mut(&mut self, index: (usize, usize)) -> &mut f64 {
+    return &mut self[index.1][index.0];
+  }
+}
+
+impl From<[[f64; 4]; 4]> for double4x4 {
+  #[inline(always)]
+  fn from(x: [[f64; 4]; 4]) -> double4x4 {
+    return double4x4(double4::from(x[0]), double4::from(x[1]), double4::from(x[2]), double4::from(x[3]));
+  }
+}
+
+impl From<double4x4> for [[f64; 4]; 4] {
+  #[inline(always)]
+  fn from(x: double4x4) -> [[f64; 4]; 4] {
+    return [x.0.into(), x.1.into(), x.2.into(), x.3.into()];
+  }
+}
+
+impl From<float4x4> for double4x4 {
+  #[inline(always)]
+  fn from(x: float4x4) -> double4x4 {
+    return x.to_double();
+  }
+}
+
+impl Determinant for double4x4 {
+  type Scalar = f64;
+
+  #[inline(always)]
+  fn determinant(self) -> f64 {
+    let a = double3((self.0).0, (self.0).1, (self.0).2);
+    let b = double3((self.1).0, (self.1).1, (self.1).2);
+    let c = double3((self.2).0, (self.2).1, (self.2).2);
+    let d = double3((self.3).0, (self.3).1, (self.3).2);
+
+    let u = a * (self.1).3 - b * (self.0).3;
+    let v = c * (self.3).3 - d * (self.2).3;
+
+    return dot(cross(a, b), v) + dot(cross(c, d), u);
+  }
+}
+
+impl Matrix for double4x4 {
+  type Scalar = f64;
+  type Column = double4;
+  type Row = double4;
+  type Transpose = double4x4;
+  type FloatMatrix = float4x4;
+  type DoubleMatrix = double4x4;
+
+  const ROWS: usize = 4;
+  const COLUMNS: usize = 4;
+
+  #[inline(always)]
+  fn column(self, index: usize) -> double4 {
+    return self[index];
+  }
+
+  #[inline(always)]
+  fn row(self, index: usize) -> double4 {
+    return double4(self.0[index], self.1[index], self.2[index], self.3[index]);
+  }
+
+  #[inline(always)]
+  fn map(self, f: &Fn(f64) -> f64) -> double4x4 {
+    return double4x4(self.0.map_unary(f), self.1.map_unary(f), self.2.map_unary(f), self.3.map_unary(f));
+  }
+
+  #[inline(always)]
+  fn transpose(self) -> double4x4 {
+    return double4x4::transpose(self);
+  }
+
+  #[inline(always)]
+  fn to_float(self) -> float4x4 {
+    return float4x4(self.0.to_float(), self.1.to_float(), self.2.to_float(), self.3.to_float());
+  }
+
+  #[inline(always)]
+  fn to_double(self) -> double4x4 {
+    return double4x4(self.0.to_double(), self.1.to_double(), self.2.to_double(), self.3.to_double());
+  }
+}
+
+impl SquareMatrix for double4x4 {
+  #[inline(always)]
+  fn identity() -> double4x4 {
+    return double4x4::identity();
+  }
+
+  #[inline(always)]
+  fn inverse(self) -> double4x4 {
+    return double4x4::inverse(self);
+  }
+
+  #[inline(always)]
+  fn trace(self) -> f64 {
+    return double4x4::trace(self);
+  }
+}
+
 impl double4x4 {
   #[inline(always)]
   pub fn from_columns(c0: double4, c1: double4, c2: double4, c3: double4) -> double4x4 {
@@ -88,16 +358,38 @@ impl double4x4 {
     return double4x4(r0, r1, r2, r3).transpose();
   }
 
+  #[inline(always)]
+  pub fn from_row_major(x: [[f64; 4]; 4]) -> double4x4 {
+    return double4x4::from_rows(double4::from(x[0]), double4::from(x[1]), double4::from(x[2]), double4::from(x[3]));
+  }
+
+  #[inline(always)]
+  pub fn to_row_major(self) -> [[f64; 4]; 4] {
+    let t = self.transpose();
+
+    return [t.0.into(), t.1.into(), t.2.into(), t.3.into()];
+  }
+
   #[inline(always)]
   pub fn identity() -> double4x4 {
     return double4x4(double4(1.0, 0.0, 0.0, 0.0), double4(0.0, 1.0, 0.0, 0.0), double4(0.0, 0.0, 1.0, 0.0), double4(0.0, 0.0, 0.0, 1.0));
   }
 
+  #[inline(always)]
+  pub fn from_diagonal(d: double4) -> double4x4 {
+    return double4x4(double4(d.0, 0.0, 0.0, 0.0), double4(0.0, d.1, 0.0, 0.0), double4(0.0, 0.0, d.2, 0.0), double4(0.0, 0.0, 0.0, d.3));
+  }
+
   #[inline(always)]
   pub fn from_scale(scale: f64) -> double4x4 {
     return double4x4(double4(scale, 0.0, 0.0, 0.0), double4(0.0, scale, 0.0, 0.0), double4(0.0, 0.0, scale, 0.0), double4(0.0, 0.0, 0.0, 1.0));
   }
 
+  #[inline(always)]
+  pub fn from_scale_vector(scale: double3) -> double4x4 {
+    return double4x4(double4(scale.0, 0.0, 0.0, 0.0), double4(0.0, scale.1, 0.0, 0.0), double4(0.0, 0.0, scale.2, 0.0), double4(0.0, 0.0, 0.0, 1.0));
+  }
+
   #[inline(always)]
   pub fn from_translation(x: f64, y: f64, z: f64) -> double4x4 {
     return double4x4(double4(1.0, 0.0, 0.0, 0.0), double4(0.0, 1.0, 0.0, 0.0), double4(0.0, 0.0, 1.0, 0.0), double4(x, y, z, 1.0));
@@ -105,9 +397,9 @@ impl double4x4 {
 
   #[inline(always)]
   pub fn from_euler_angles(roll: f64, pitch: f64, yaw: f64) -> double4x4 {
-    let (sr, cr) = roll.sin_cos();
-    let (sp, cp) = pitch.sin_cos();
-    let (sy, cy) = yaw.sin_cos();
+    let (sr, cr) = scalar::FloatScalar::sin_cos(roll);
+    let (sp, cp) = scalar::FloatScalar::sin_cos(pitch);
+    let (sy, cy) = scalar::FloatScalar::sin_cos(yaw);
 
     return double4x4(
       double4(cy * cp, sy * cp, -sp, 0.0),
@@ -146,7 +438,7 @@ impl double4x4 {
 
   #[inline(always)]
   pub fn perspective_fov(fov_y: f64, aspect: f64, near: f64, far: f64) -> double4x4 {
-    let y_scale = 1.0 / (0.5 * fov_y).tan();
+    let y_scale = 1.0 / scalar::FloatScalar::tan(0.5 * fov_y);
     let x_scale = y_scale / aspect;
     let z_scale = far / (far - near);
 
@@ -179,6 +471,11 @@ impl double4x4 {
     return double4x4(a * x.0 + b * y.0, a * x.1 + b * y.1, a * x.2 + b * y.2, a * x.3 + b * y.3);
   }
 
+  #[inline(always)]
+  pub fn hadamard(self, other: double4x4) -> double4x4 {
+    return double4x4(self.0 * other.0, self.1 * other.1, self.2 * other.2, self.3 * other.3);
+  }
+
   #[inline(always)]
   pub fn transpose(self) -> double4x4 {
     let c0 = double4((self.0).0, (self.1).0, (self.2).0, (self.3).0);
@@ -189,8 +486,149 @@ impl double4x4 {
     return double4x4(c0, c1, c2, c3);
   }
 
+  #[inline(always)]
+  pub fn diagonal(self) -> double4 {
+    return double4((self.0).0, (self.1).1, (self.2).2, (self.3).3);
+  }
+
+  #[inline(always)]
+  pub fn trace(self) -> f64 {
+    return (self.0).0 + (self.1).1 + (self.2).2 + (self.3).3;
+  }
+
+  #[inline(always)]
+  pub fn upper_left_2x2(self) -> double2x2 {
+    return double2x2(double2((self.0).0, (self.0).1), double2((self.1).0, (self.1).1));
+  }
+
+  #[inline(always)]
+  pub fn upper_left_3x3(self) -> double3x3 {
+    return double3x3(double3((self.0).0, (self.0).1, (self.0).2), double3((self.1).0, (self.1).1, (self.1).2), double3((self.2).0, (self.2).1, (self.2).2));
+  }
+
+  #[inline(always)]
+  pub fn to_affine(self) -> double4x3 {
+    return double4x3(double3((self.0).0, (self.0).1, (self.0).2), double3((self.1).0, (self.1).1, (self.1).2), double3((self.2).0, (self.2).1, (self.2).2), double3((self.3).0, (self.3).1, (self.3).2));
+  }
+
+  #[inline(always)]
+  pub fn to_affine_rows(self) -> double3x4 {
+    return double3x4(double4((self.0).0, (self.1).0, (self.2).0, (self.3).0), double4((self.0).1, (self.1).1, (self.2).1, (self.3).1), double4((self.0).2, (self.1).2, (self.2).2, (self.3).2));
+  }
+
+  #[inline(always)]
+  pub fn from_affine_rows(m: double3x4) -> double4x4 {
+    return double4x4(double4((m.0).0, (m.1).0, (m.2).0, 0.0), double4((m.0).1, (m.1).1, (m.2).1, 0.0), double4((m.0).2, (m.1).2, (m.2).2, 0.0), double4((m.0).3, (m.1).3, (m.2).3, 1.0));
+  }
+
+  #[inline]
+  pub fn minor(self, row: usize, column: usize) -> double3x3 {
+    assert!(row < 4 && column < 4, "minor ({}, {}) out of range for double4x4", row, column);
+
+    let mut m = double3x3::default();
+
+    for c in 0 .. 3 {
+      for r in 0 .. 3 {
+        m[(r, c)] = self[(r + (r >= row) as usize, c + (c >= column) as usize)];
+      }
+    }
+
+    return m;
+  }
+
+  #[cfg(not(feature = "portable"))]
   #[inline(always)]
   pub fn inverse(self) -> double4x4 {
     return unsafe { __invert_d4(self) };
   }
+
+  #[cfg(feature = "portable")]
+  #[inline(always)]
+  pub fn inverse(self) -> double4x4 {
+    return self.inverse_with_det().0;
+  }
+
+  #[cfg(not(feature = "portable"))]
+  #[inline(always)]
+  pub fn inverse_with_det(self) -> (double4x4, f64) {
+    return (self.inverse(), self.determinant());
+  }
+
+  #[cfg(feature = "portable")]
+  #[inline(always)]
+  pub fn inverse_with_det(self) -> (double4x4, f64) {
+    let a = double3((self.0).0, (self.0).1, (self.0).2);
+    let b = double3((self.1).0, (self.1).1, (self.1).2);
+    let c = double3((self.2).0, (self.2).1, (self.2).2);
+    let d = double3((self.3).0, (self.3).1, (self.3).2);
+
+    let x = (self.0).3;
+    let y = (self.1).3;
+    let z = (self.2).3;
+    let w = (self.3).3;
+
+    let s = cross(a, b);
+    let t = cross(c, d);
+    let u = a * y - b * x;
+    let v = c * w - d * z;
+
+    let det = dot(s, v) + dot(t, u);
+
+    let r0 = cross(b, v) + t * y;
+    let r1 = cross(v, a) - t * x;
+    let r2 = cross(d, u) + s * w;
+    let r3 = cross(u, c) - s * z;
+
+    return (double4x4::from_rows(double4(r0.0, r0.1, r0.2, -dot(b, t)), double4(r1.0, r1.1, r1.2, dot(a, t)), double4(r2.0, r2.1, r2.2, -dot(d, s)), double4(r3.0, r3.1, r3.2, dot(c, s))) * (1.0 / det), det);
+  }
+
+  #[inline(always)]
+  pub fn try_inverse(self) -> Option<double4x4> {
+    return self.try_inverse_epsilon(0.0);
+  }
+
+  #[inline]
+  pub fn try_inverse_epsilon(self, epsilon: f64) -> Option<double4x4> {
+    let (inverse, det) = self.inverse_with_det();
+
+    if (det > epsilon || det < -epsilon) && (inverse.0.is_finite() & inverse.1.is_finite() & inverse.2.is_finite() & inverse.3.is_finite()).all() {
+      return Some(inverse);
+    }
+
+    return None;
+  }
+
+  #[inline]
+  pub fn condition_number_estimate(self) -> f64 {
+    match self.try_inverse() {
+      Some(inverse) => {
+        let a = self.0.abs() + self.1.abs() + self.2.abs() + self.3.abs();
+        let b = inverse.0.abs() + inverse.1.abs() + inverse.2.abs() + inverse.3.abs();
+
+        return a.reduce_max() * b.reduce_max();
+      },
+      None => return std::f64::INFINITY
+    }
+  }
+
+  #[inline(always)]
+  pub fn inverse_affine(self) -> double4x4 {
+    let m = double3x3(double3((self.0).0, (self.0).1, (self.0).2), double3((self.1).0, (self.1).1, (self.1).2), double3((self.2).0, (self.2).1, (self.2).2)).inverse();
+    let t = -(m * double3((self.3).0, (self.3).1, (self.3).2));
+
+    return double4x4(double4((m.0).0, (m.0).1, (m.0).2, 0.0), double4((m.1).0, (m.1).1, (m.1).2, 0.0), double4((m.2).0, (m.2).1, (m.2).2, 0.0), double4(t.0, t.1, t.2, 1.0));
+  }
+
+  #[inline(always)]
+  pub fn inverse_rigid(self) -> double4x4 {
+    let m = double3x3(double3((self.0).0, (self.0).1, (self.0).2), double3((self.1).0, (self.1).1, (self.1).2), double3((self.2).0, (self.2).1, (self.2).2)).transpose();
+    let t = -(m * double3((self.3).0, (self.3).1, (self.3).2));
+
+    return double4x4(double4((m.0).0, (m.0).1, (m.0).2, 0.0), double4((m.1).0, (m.1).1, (m.1).2, 0.0), double4((m.2).0, (m.2).1, (m.2).2, 0.0), double4(t.0, t.1, t.2, 1.0));
+  }
+
+  #[inline(always)]
+  pub fn normal_matrix(self) -> double3x3 {
+    return double3x3(double3((self.0).0, (self.0).1, (self.0).2), double3((self.1).0, (self.1).1, (self.1).2), double3((self.2).0, (self.2).1, (self.2).2)).inverse().transpose();
+  }
 }
@@ -1,6 +1,7 @@
 use std;
 use ::*;
 
+#[cfg(not(feature = "portable"))]
 extern {
   fn __invert_f2(a: float2x2) -> float2x2;
 }
@@ -23,6 +24,15 @@ impl std::ops::Sub for float2x2 {
   }
 }
 
+impl std::ops::Neg for float2x2 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn neg(self) -> Self {
+    return float2x2(-self.0, -self.1);
+  }
+}
+
 impl std::ops::Mul<float2x2> for float2x2 {
   type Output = float2x2;
 
@@ -32,6 +42,24 @@ impl std::ops::Mul<float2x2> for float2x2 {
   }
 }
 
+impl std::ops::Mul<float3x2> for float2x2 {
+  type Output = float3x2;
+
+  #[inline(always)]
+  fn mul(self, other: float3x2) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
+impl std::ops::Mul<float4x2> for float2x2 {
+  type Output = float4x2;
+
+  #[inline(always)]
+  fn mul(self, other: float4x2) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<float2> for float2x2 {
   type Output = float2;
 
@@ -52,6 +80,89 @@ impl std::ops::Mul<f32> for float2x2 {
   }
 }
 
+impl std::ops::Div<f32> for float2x2 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn div(self, other: f32) -> Self {
+    let a = float2::broadcast(other);
+
+    return float2x2(self.0 / a, self.1 / a);
+  }
+}
+
+impl std::ops::Mul<float2x2> for float2 {
+  type Output = float2;
+
+  #[inline(always)]
+  fn mul(self, other: float2x2) -> Self::Output {
+    return other.transpose().dot(self);
+  }
+}
+
+impl std::ops::AddAssign for float2x2 {
+  #[inline(always)]
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl std::ops::SubAssign for float2x2 {
+  #[inline(always)]
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl std::ops::MulAssign for float2x2 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: Self) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::MulAssign<f32> for float2x2 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: f32) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::DivAssign<f32> for float2x2 {
+  #[inline(always)]
+  fn div_assign(&mut self, other: f32) {
+    *self = *self / other;
+  }
+}
+
+impl std::iter::Sum for float2x2 {
+  #[inline]
+  fn sum<I: Iterator<Item=float2x2>>(iter: I) -> float2x2 {
+    return iter.fold(float2x2(float2::from(0), float2::from(0)), |a, b| a + b);
+  }
+}
+
+impl<'a> std::iter::Sum<&'a float2x2> for float2x2 {
+  #[inline]
+  fn sum<I: Iterator<Item=&'a float2x2>>(iter: I) -> float2x2 {
+    return iter.fold(float2x2(float2::from(0), float2::from(0)), |a, b| a + *b);
+  }
+}
+
+impl std::iter::Product for float2x2 {
+  #[inline]
+  fn product<I: Iterator<Item=float2x2>>(iter: I) -> float2x2 {
+    return iter.fold(float2x2::identity(), |a, b| a * b);
+  }
+}
+
+impl<'a> std::iter::Product<&'a float2x2> for float2x2 {
+  #[inline]
+  fn product<I: Iterator<Item=&'a float2x2>>(iter: I) -> float2x2 {
+    return iter.fold(float2x2::identity(), |a, b| a * *b);
+  }
+}
+
 impl Dot<float2x2> for float2x2 {
   type DotProduct = float2x2;
 
@@ -61,6 +172,24 @@ impl Dot<float2x2> for float2x2 {
   }
 }
 
+impl Dot<float3x2> for float2x2 {
+  type DotProduct = float3x2;
+
+  #[inline(always)]
+  fn dot(self, other: float3x2) -> Self::DotProduct {
+    return float3x2(self.dot(other.0), self.dot(other.1), self.dot(other.2));
+  }
+}
+
+impl Dot<float4x2> for float2x2 {
+  type DotProduct = float4x2;
+
+  #[inline(always)]
+  fn dot(self, other: float4x2) -> Self::DotProduct {
+    return float4x2(self.dot(other.0), self.dot(other.1), self.dot(other.2), self.dot(other.3));
+  }
+}
+
 impl Dot<float2> for float2x2 {
   type DotProduct = float2;
 
@@ -77,6 +206,135 @@ impl PartialEq for float2x2 {
   }
 }
 
+impl std::ops::Index<usize> for float2x2 {
+  type Output = float2;
+
+  #[inline]
+  fn index(&self, index: usize) -> &float2 {
+    match index {
+      0 => return &self.0,
+      1 => return &self.1,
+      _ => panic!("column index {} out of range for float2x2", index)
+    }
+  }
+}
+
+impl std::ops::IndexMut<usize> for float2x2 {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut float2 {
+    match index {
+      0 => return &mut self.0,
+      1 => return &mut self.1,
+      _ => panic!("column index {} out of range for float2x2", index)
+    }
+  }
+}
+
+impl std::ops::Index<(usize, usize)> for float2x2 {
+  type Output = f32;
+
+  #[inline(always)]
+  fn index(&self, index: (usize, usize)) -> &f32 {
+    return &self[index.1][index.0];
+  }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for float2x2 {
+  #[inline(always)]
+  fn index_mut(&mut self, index: (usize, usize)) -> &mut f32 {
+    return &mut self[index.1][index.0];
+  }
+}
+
+impl From<[[f32; 2]; 2]> for float2x2 {
+  #[inline(always)]
+  fn from(x: [[f32; 2]; 2]) -> float2x2 {
+    return float2x2(float2::from(x[0]), float2::from(x[1]));
+  }
+}
+
+impl From<float2x2> for [[f32; 2]; 2] {
+  #[inline(always)]
+  fn from(x: float2x2) -> [[f32; 2]; 2] {
+    return [x.0.into(), x.1.into()];
+  }
+}
+
+impl From<double2x2> for float2x2 {
+  #[inline(always)]
+  fn from(x: double2x2) -> float2x2 {
+    return x.to_float();
+  }
+}
+
+impl Determinant for float2x2 {
+  type Scalar = f32;
+
+  #[inline(always)]
+  fn determinant(self) -> f32 {
+    return (self.0).0 * (self.1).1 - (self.0).1 * (self.1).0;
+  }
+}
+
+impl Matrix for float2x2 {
+  type Scalar = f32;
+  type Column = float2;
+  type Row = float2;
+  type Transpose = float2x2;
+  type FloatMatrix = float2x2;
+  type DoubleMatrix = double2x2;
+
+  const ROWS: usize = 2;
+  const COLUMNS: usize = 2;
+
+  #[inline(always)]
+  fn column(self, index: usize) -> float2 {
+    return self[index];
+  }
+
+  #[inline(always)]
+  fn row(self, index: usize) -> float2 {
+    return float2(self.0[index], self.1[index]);
+  }
+
+  #[inline(always)]
+  fn map(self, f: &Fn(f32) -> f32) -> float2x2 {
+    return float2x2(self.0.map_unary(f), self.1.map_unary(f));
+  }
+
+  #[inline(always)]
+  fn transpose(self) -> float2x2 {
+    return float2x2::transpose(self);
+  }
+
+  #[inline(always)]
+  fn to_float(self) -> float2x2 {
+    return float2x2(self.0.to_float(), self.1.to_float());
+  }
+
+  #[inline(always)]
+  fn to_double(self) -> double2x2 {
+    return double2x2(self.0.to_double(), self.1.to_double());
+  }
+}
+
+impl SquareMatrix for float2x2 {
+  #[inline(always)]
+  fn identity() -> float2x2 {
+    return float2x2::identity();
+  }
+
+  #[inline(always)]
+  fn inverse(self) -> float2x2 {
+    return float2x2::inverse(self);
+  }
+
+  #[inline(always)]
+  fn trace(self) -> f32 {
+    return float2x2::trace(self);
+  }
+}
+
 impl float2x2 {
   #[inline(always)]
   pub fn from_columns(c0: float2, c1: float2) -> float2x2 {
@@ -88,11 +346,28 @@ impl float2x2 {
     return float2x2(r0, r1).transpose();
   }
 
+  #[inline(always)]
+  pub fn from_row_major(x: [[f32; 2]; 2]) -> float2x2 {
+    return float2x2::from_rows(float2::from(x[0]), float2::from(x[1]));
+  }
+
+  #[inline(always)]
+  pub fn to_row_major(self) -> [[f32; 2]; 2] {
+    let t = self.transpose();
+
+    return [t.0.into(), t.1.into()];
+  }
+
   #[inline(always)]
   pub fn identity() -> float2x2 {
     return float2x2(float2(1.0, 0.0), float2(0.0, 1.0));
   }
 
+  #[inline(always)]
+  pub fn from_diagonal(d: float2) -> float2x2 {
+    return float2x2(float2(d.0, 0.0), float2(0.0, d.1));
+  }
+
   #[inline(always)]
   pub fn linear_combination(a: f32, x: float2x2, b: f32, y: float2x2) -> float2x2 {
     let a = float2::broadcast(a);
@@ -100,6 +375,11 @@ impl float2x2 {
     return float2x2(a * x.0 + b * y.0, a * x.1 + b * y.1);
   }
 
+  #[inline(always)]
+  pub fn hadamard(self, other: float2x2) -> float2x2 {
+    return float2x2(self.0 * other.0, self.1 * other.1);
+  }
+
   #[inline(always)]
   pub fn transpose(self) -> float2x2 {
     let c0 = float2((self.0).0, (self.1).0);
@@ -108,8 +388,73 @@ impl float2x2 {
     return float2x2(c0, c1);
   }
 
+  #[inline(always)]
+  pub fn diagonal(self) -> float2 {
+    return float2((self.0).0, (self.1).1);
+  }
+
+  #[inline(always)]
+  pub fn trace(self) -> f32 {
+    return (self.0).0 + (self.1).1;
+  }
+
+  #[inline(always)]
+  pub fn to_homogeneous(self) -> float3x3 {
+    return float3x3(float3((self.0).0, (self.0).1, 0.0), float3((self.1).0, (self.1).1, 0.0), float3(0.0, 0.0, 1.0));
+  }
+
+  #[cfg(not(feature = "portable"))]
   #[inline(always)]
   pub fn inverse(self) -> float2x2 {
     return unsafe { __invert_f2(self) };
   }
+
+  #[cfg(feature = "portable")]
+  #[inline(always)]
+  pub fn inverse(self) -> float2x2 {
+    return self.inverse_with_det().0;
+  }
+
+  #[cfg(not(feature = "portable"))]
+  #[inline(always)]
+  pub fn inverse_with_det(self) -> (float2x2, f32) {
+    return (self.inverse(), self.determinant());
+  }
+
+  #[cfg(feature = "portable")]
+  #[inline(always)]
+  pub fn inverse_with_det(self) -> (float2x2, f32) {
+    let det = (self.0).0 * (self.1).1 - (self.0).1 * (self.1).0;
+
+    return (float2x2(float2((self.1).1, -(self.0).1), float2(-(self.1).0, (self.0).0)) * (1.0 / det), det);
+  }
+
+  #[inline(always)]
+  pub fn try_inverse(self) -> Option<float2x2> {
+    return self.try_inverse_epsilon(0.0);
+  }
+
+  #[inline]
+  pub fn try_inverse_epsilon(self, epsilon: f32) -> Option<float2x2> {
+    let (inverse, det) = self.inverse_with_det();
+
+    if (det > epsilon || det < -epsilon) && (inverse.0.is_finite() & inverse.1.is_finite()).all() {
+      return Some(inverse);
+    }
+
+    return None;
+  }
+
+  #[inline]
+  pub fn condition_number_estimate(self) -> f32 {
+    match self.try_inverse() {
+      Some(inverse) => {
+        let a = self.0.abs() + self.1.abs();
+        let b = inverse.0.abs() + inverse.1.abs();
+
+        return a.reduce_max() * b.reduce_max();
+      },
+      None => return std::f32::INFINITY
+    }
+  }
 }
@@ -19,6 +19,33 @@ impl std::ops::Sub for float2x4 {
   }
 }
 
+impl std::ops::Neg for float2x4 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn neg(self) -> Self {
+    return float2x4(-self.0, -self.1);
+  }
+}
+
+impl std::ops::Mul<float2x2> for float2x4 {
+  type Output = float2x4;
+
+  #[inline(always)]
+  fn mul(self, other: float2x2) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
+impl std::ops::Mul<float3x2> for float2x4 {
+  type Output = float3x4;
+
+  #[inline(always)]
+  fn mul(self, other: float3x2) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<float4x2> for float2x4 {
   type Output = float4x4;
 
@@ -48,6 +75,86 @@ impl std::ops::Mul<f32> for float2x4 {
   }
 }
 
+impl std::ops::Div<f32> for float2x4 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn div(self, other: f32) -> Self {
+    let a = float4::broadcast(other);
+
+    return float2x4(self.0 / a, self.1 / a);
+  }
+}
+
+impl std::ops::Mul<float2x4> for float4 {
+  type Output = float2;
+
+  #[inline(always)]
+  fn mul(self, other: float2x4) -> Self::Output {
+    return other.transpose().dot(self);
+  }
+}
+
+impl std::ops::AddAssign for float2x4 {
+  #[inline(always)]
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl std::ops::SubAssign for float2x4 {
+  #[inline(always)]
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl std::ops::MulAssign<f32> for float2x4 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: f32) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::DivAssign<f32> for float2x4 {
+  #[inline(always)]
+  fn div_assign(&mut self, other: f32) {
+    *self = *self / other;
+  }
+}
+
+impl std::iter::Sum for float2x4 {
+  #[inline]
+  fn sum<I: Iterator<Item=float2x4>>(iter: I) -> float2x4 {
+    return iter.fold(float2x4(float4::from(0), float4::from(0)), |a, b| a + b);
+  }
+}
+
+impl<'a> std::iter::Sum<&'a float2x4> for float2x4 {
+  #[inline]
+  fn sum<I: Iterator<Item=&'a float2x4>>(iter: I) -> float2x4 {
+    return iter.fold(float2x4(float4::from(0), float4::from(0)), |a, b| a + *b);
+  }
+}
+
+impl Dot<float2x2> for float2x4 {
+  type DotProduct = float2x4;
+
+  #[inline(always)]
+  fn dot(self, other: float2x2) -> Self::DotProduct {
+    return float2x4(self.dot(other.0), self.dot(other.1));
+  }
+}
+
+impl Dot<float3x2> for float2x4 {
+  type DotProduct = float3x4;
+
+  #[inline(always)]
+  fn dot(self, other: float3x2) -> Self::DotProduct {
+    return float3x4(self.dot(other.0), self.dot(other.1), self.dot(other.2));
+  }
+}
+
 impl Dot<float4x2> for float2x4 {
   type DotProduct = float4x4;
 
@@ -73,6 +180,109 @@ impl PartialEq for float2x4 {
   }
 }
 
+impl std::ops::Index<usize> for float2x4 {
+  type Output = float4;
+
+  #[inline]
+  fn index(&self, index: usize) -> &float4 {
+    match index {
+      0 => return &self.0,
+      1 => return &self.1,
+      _ => panic!("column index {} out of range for float2x4", index)
+    }
+  }
+}
+
+impl std::ops::IndexMut<usize> for float2x4 {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut float4 {
+    match index {
+      0 => return &mut self.0,
+      1 => return &mut self.1,
+      _ => panic!("column index {} out of range for float2x4", index)
+    }
+  }
+}
+
+impl std::ops::Index<(usize, usize)> for float2x4 {
+  type Output = f32;
+
+  #[inline(always)]
+  fn index(&self, index: (usize, usize)) -> &f32 {
+    return &self[index.1][index.0];
+  }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for float2x4 {
+  #[inline(always)]
+  fn index_mut(&mut self, index: (usize, usize)) -> &mut f32 {
+    return &mut self[index.1][index.0];
+  }
+}
+
+impl From<[[f32; 4]; 2]> for float2x4 {
+  #[inline(always)]
+  fn from(x: [[f32; 4]; 2]) -> float2x4 {
+    return float2x4(float4::from(x[0]), float4::from(x[1]));
+  }
+}
+
+impl From<float2x4> for [[f32; 4]; 2] {
+  #[inline(always)]
+  fn from(x: float2x4) -> [[f32; 4]; 2] {
+    return [x.0.into(), x.1.into()];
+  }
+}
+
+impl From<double2x4> for float2x4 {
+  #[inline(always)]
+  fn from(x: double2x4) -> float2x4 {
+    return x.to_float();
+  }
+}
+
+impl Matrix for float2x4 {
+  type Scalar = f32;
+  type Column = float4;
+  type Row = float2;
+  type Transpose = float4x2;
+  type FloatMatrix = float2x4;
+  type DoubleMatrix = double2x4;
+
+  const ROWS: usize = 4;
+  const COLUMNS: usize = 2;
+
+  #[inline(always)]
+  fn column(self, index: usize) -> float4 {
+    return self[index];
+  }
+
+  #[inline(always)]
+  fn row(self, index: usize) -> float2 {
+    return float2(self.0[index], self.1[index]);
+  }
+
+  #[inline(always)]
+  fn map(self, f: &Fn(f32) -> f32) -> float2x4 {
+    return float2x4(self.0.map_unary(f), self.1.map_unary(f));
+  }
+
+  #[inline(always)]
+  fn transpose(self) -> float4x2 {
+    return float2x4::transpose(self);
+  }
+
+  #[inline(always)]
+  fn to_float(self) -> float2x4 {
+    return float2x4(self.0.to_float(), self.1.to_float());
+  }
+
+  #[inline(always)]
+  fn to_double(self) -> double2x4 {
+    return double2x4(self.0.to_double(), self.1.to_double());
+  }
+}
+
 impl float2x4 {
   #[inline(always)]
   pub fn from_columns(c0: float4, c1: float4) -> float2x4 {
@@ -84,6 +294,18 @@ impl float2x4 {
     return float4x2(r0, r1, r2, r3).transpose();
   }
 
+  #[inline(always)]
+  pub fn from_row_major(x: [[f32; 2]; 4]) -> float2x4 {
+    return float2x4::from_rows(float2::from(x[0]), float2::from(x[1]), float2::from(x[2]), float2::from(x[3]));
+  }
+
+  #[inline(always)]
+  pub fn to_row_major(self) -> [[f32; 2]; 4] {
+    let t = self.transpose();
+
+    return [t.0.into(), t.1.into(), t.2.into(), t.3.into()];
+  }
+
   #[inline(always)]
   pub fn linear_combination(a: f32, x: float2x4, b: f32, y: float2x4) -> float2x4 {
     let a = float4::broadcast(a);
@@ -91,6 +313,11 @@ impl float2x4 {
     return float2x4(a * x.0 + b * y.0, a * x.1 + b * y.1);
   }
 
+  #[inline(always)]
+  pub fn hadamard(self, other: float2x4) -> float2x4 {
+    return float2x4(self.0 * other.0, self.1 * other.1);
+  }
+
   #[inline(always)]
   pub fn transpose(self) -> float4x2 {
     let c0 = float2((self.0).0, (self.1).0);
@@ -100,4 +327,9 @@ impl float2x4 {
 
     return float4x2(c0, c1, c2, c3);
   }
+
+  #[inline(always)]
+  pub fn upper_left_2x2(self) -> float2x2 {
+    return float2x2(float2((self.0).0, (self.0).1), float2((self.1).0, (self.1).1));
+  }
 }
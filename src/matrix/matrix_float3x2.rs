@@ -19,6 +19,15 @@ impl std::ops::Sub for float3x2 {
   }
 }
 
+impl std::ops::Neg for float3x2 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn neg(self) -> Self {
+    return float3x2(-self.0, -self.1, -self.2);
+  }
+}
+
 impl std::ops::Mul<float2x3> for float3x2 {
   type Output = float2x2;
 
@@ -28,6 +37,24 @@ impl std::ops::Mul<float2x3> for float3x2 {
   }
 }
 
+impl std::ops::Mul<float3x3> for float3x2 {
+  type Output = float3x2;
+
+  #[inline(always)]
+  fn mul(self, other: float3x3) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
+impl std::ops::Mul<float4x3> for float3x2 {
+  type Output = float4x2;
+
+  #[inline(always)]
+  fn mul(self, other: float4x3) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<float3> for float3x2 {
   type Output = float2;
 
@@ -48,6 +75,68 @@ impl std::ops::Mul<f32> for float3x2 {
   }
 }
 
+impl std::ops::Div<f32> for float3x2 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn div(self, other: f32) -> Self {
+    let a = float2::broadcast(other);
+
+    return float3x2(self.0 / a, self.1 / a, self.2 / a);
+  }
+}
+
+impl std::ops::Mul<float3x2> for float2 {
+  type Output = float3;
+
+  #[inline(always)]
+  fn mul(self, other: float3x2) -> Self::Output {
+    return other.transpose().dot(self);
+  }
+}
+
+impl std::ops::AddAssign for float3x2 {
+  #[inline(always)]
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl std::ops::SubAssign for float3x2 {
+  #[inline(always)]
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl std::ops::MulAssign<f32> for float3x2 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: f32) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::DivAssign<f32> for float3x2 {
+  #[inline(always)]
+  fn div_assign(&mut self, other: f32) {
+    *self = *self / other;
+  }
+}
+
+impl std::iter::Sum for float3x2 {
+  #[inline]
+  fn sum<I: Iterator<Item=float3x2>>(iter: I) -> float3x2 {
+    return iter.fold(float3x2(float2::from(0), float2::from(0), float2::from(0)), |a, b| a + b);
+  }
+}
+
+impl<'a> std::iter::Sum<&'a float3x2> for float3x2 {
+  #[inline]
+  fn sum<I: Iterator<Item=&'a float3x2>>(iter: I) -> float3x2 {
+    return iter.fold(float3x2(float2::from(0), float2::from(0), float2::from(0)), |a, b| a + *b);
+  }
+}
+
 impl Dot<float2x3> for float3x2 {
   type DotProduct = float2x2;
 
@@ -57,6 +146,24 @@ impl Dot<float2x3> for float3x2 {
   }
 }
 
+impl Dot<float3x3> for float3x2 {
+  type DotProduct = float3x2;
+
+  #[inline(always)]
+  fn dot(self, other: float3x3) -> Self::DotProduct {
+    return float3x2(self.dot(other.0), self.dot(other.1), self.dot(other.2));
+  }
+}
+
+impl Dot<float4x3> for float3x2 {
+  type DotProduct = float4x2;
+
+  #[inline(always)]
+  fn dot(self, other: float4x3) -> Self::DotProduct {
+    return float4x2(self.dot(other.0), self.dot(other.1), self.dot(other.2), self.dot(other.3));
+  }
+}
+
 impl Dot<float3> for float3x2 {
   type DotProduct = float2;
 
@@ -73,6 +180,111 @@ impl PartialEq for float3x2 {
   }
 }
 
+impl std::ops::Index<usize> for float3x2 {
+  type Output = float2;
+
+  #[inline]
+  fn index(&self, index: usize) -> &float2 {
+    match index {
+      0 => return &self.0,
+      1 => return &self.1,
+      2 => return &self.2,
+      _ => panic!("column index {} out of range for float3x2", index)
+    }
+  }
+}
+
+impl std::ops::IndexMut<usize> for float3x2 {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut float2 {
+    match index {
+      0 => return &mut self.0,
+      1 => return &mut self.1,
+      2 => return &mut self.2,
+      _ => panic!("column index {} out of range for float3x2", index)
+    }
+  }
+}
+
+impl std::ops::Index<(usize, usize)> for float3x2 {
+  type Output = f32;
+
+  #[inline(always)]
+  fn index(&self, index: (usize, usize)) -> &f32 {
+    return &self[index.1][index.0];
+  }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for float3x2 {
+  #[inline(always)]
+  fn index_mut(&mut self, index: (usize, usize)) -> &mut f32 {
+    return &mut self[index.1][index.0];
+  }
+}
+
+impl From<[[f32; 2]; 3]> for float3x2 {
+  #[inline(always)]
+  fn from(x: [[f32; 2]; 3]) -> float3x2 {
+    return float3x2(float2::from(x[0]), float2::from(x[1]), float2::from(x[2]));
+  }
+}
+
+impl From<float3x2> for [[f32; 2]; 3] {
+  #[inline(always)]
+  fn from(x: float3x2) -> [[f32; 2]; 3] {
+    return [x.0.into(), x.1.into(), x.2.into()];
+  }
+}
+
+impl From<double3x2> for float3x2 {
+  #[inline(always)]
+  fn from(x: double3x2) -> float3x2 {
+    return x.to_float();
+  }
+}
+
+impl Matrix for float3x2 {
+  type Scalar = f32;
+  type Column = float2;
+  type Row = float3;
+  type Transpose = float2x3;
+  type FloatMatrix = float3x2;
+  type DoubleMatrix = double3x2;
+
+  const ROWS: usize = 2;
+  const COLUMNS: usize = 3;
+
+  #[inline(always)]
+  fn column(self, index: usize) -> float2 {
+    return self[index];
+  }
+
+  #[inline(always)]
+  fn row(self, index: usize) -> float3 {
+    return float3(self.0[index], self.1[index], self.2[index]);
+  }
+
+  #[inline(always)]
+  fn map(self, f: &Fn(f32) -> f32) -> float3x2 {
+    return float3x2(self.0.map_unary(f), self.1.map_unary(f), self.2.map_unary(f));
+  }
+
+  #[inline(always)]
+  fn transpose(self) -> float2x3 {
+    return float3x2::transpose(self);
+  }
+
+  #[inline(always)]
+  fn to_float(self) -> float3x2 {
+    return float3x2(self.0.to_float(), self.1.to_float(), self.2.to_float());
+  }
+
+  #[inline(always)]
+  fn to_double(self) -> double3x2 {
+    return double3x2(self.0.to_double(), self.1.to_double(), self.2.to_double());
+  }
+}
+
 impl float3x2 {
   #[inline(always)]
   pub fn from_columns(c0: float2, c1: float2, c2: float2) -> float3x2 {
@@ -84,6 +296,18 @@ impl float3x2 {
     return float2x3(r0, r1).transpose();
   }
 
+  #[inline(always)]
+  pub fn from_row_major(x: [[f32; 3]; 2]) -> float3x2 {
+    return float3x2::from_rows(float3::from(x[0]), float3::from(x[1]));
+  }
+
+  #[inline(always)]
+  pub fn to_row_major(self) -> [[f32; 3]; 2] {
+    let t = self.transpose();
+
+    return [t.0.into(), t.1.into()];
+  }
+
   #[inline(always)]
   pub fn linear_combination(a: f32, x: float3x2, b: f32, y: float3x2) -> float3x2 {
     let a = float2::broadcast(a);
@@ -91,6 +315,11 @@ impl float3x2 {
     return float3x2(a * x.0 + b * y.0, a * x.1 + b * y.1, a * x.2 + b * y.2);
   }
 
+  #[inline(always)]
+  pub fn hadamard(self, other: float3x2) -> float3x2 {
+    return float3x2(self.0 * other.0, self.1 * other.1, self.2 * other.2);
+  }
+
   #[inline(always)]
   pub fn transpose(self) -> float2x3 {
     let c0 = float3((self.0).0, (self.1).0, (self.2).0);
@@ -98,4 +327,14 @@ impl float3x2 {
 
     return float2x3(c0, c1);
   }
+
+  #[inline(always)]
+  pub fn upper_left_2x2(self) -> float2x2 {
+    return float2x2(self.0, self.1);
+  }
+
+  #[inline(always)]
+  pub fn to_homogeneous(self) -> float3x3 {
+    return float3x3(float3((self.0).0, (self.0).1, 0.0), float3((self.1).0, (self.1).1, 0.0), float3((self.2).0, (self.2).1, 1.0));
+  }
 }
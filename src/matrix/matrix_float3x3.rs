@@ -1,6 +1,7 @@
 use std;
 use ::*;
 
+#[cfg(not(feature = "portable"))]
 extern {
   fn __invert_f3(a: float3x3) -> float3x3;
 }
@@ -23,6 +24,24 @@ impl std::ops::Sub for float3x3 {
   }
 }
 
+impl std::ops::Neg for float3x3 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn neg(self) -> Self {
+    return float3x3(-self.0, -self.1, -self.2);
+  }
+}
+
+impl std::ops::Mul<float2x3> for float3x3 {
+  type Output = float2x3;
+
+  #[inline(always)]
+  fn mul(self, other: float2x3) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<float3x3> for float3x3 {
   type Output = float3x3;
 
@@ -32,6 +51,15 @@ impl std::ops::Mul<float3x3> for float3x3 {
   }
 }
 
+impl std::ops::Mul<float4x3> for float3x3 {
+  type Output = float4x3;
+
+  #[inline(always)]
+  fn mul(self, other: float4x3) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<float3> for float3x3 {
   type Output = float3;
 
@@ -52,6 +80,98 @@ impl std::ops::Mul<f32> for float3x3 {
   }
 }
 
+impl std::ops::Div<f32> for float3x3 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn div(self, other: f32) -> Self {
+    let a = float3::broadcast(other);
+
+    return float3x3(self.0 / a, self.1 / a, self.2 / a);
+  }
+}
+
+impl std::ops::Mul<float3x3> for float3 {
+  type Output = float3;
+
+  #[inline(always)]
+  fn mul(self, other: float3x3) -> Self::Output {
+    return other.transpose().dot(self);
+  }
+}
+
+impl std::ops::AddAssign for float3x3 {
+  #[inline(always)]
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl std::ops::SubAssign for float3x3 {
+  #[inline(always)]
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl std::ops::MulAssign for float3x3 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: Self) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::MulAssign<f32> for float3x3 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: f32) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::DivAssign<f32> for float3x3 {
+  #[inline(always)]
+  fn div_assign(&mut self, other: f32) {
+    *self = *self / other;
+  }
+}
+
+impl std::iter::Sum for float3x3 {
+  #[inline]
+  fn sum<I: Iterator<Item=float3x3>>(iter: I) -> float3x3 {
+    return iter.fold(float3x3(float3::from(0), float3::from(0), float3::from(0)), |a, b| a + b);
+  }
+}
+
+impl<'a> std::iter::Sum<&'a float3x3> for float3x3 {
+  #[inline]
+  fn sum<I: Iterator<Item=&'a float3x3>>(iter: I) -> float3x3 {
+    return iter.fold(float3x3(float3::from(0), float3::from(0), float3::from(0)), |a, b| a + *b);
+  }
+}
+
+impl std::iter::Product for float3x3 {
+  #[inline]
+  fn product<I: Iterator<Item=float3x3>>(iter: I) -> float3x3 {
+    return iter.fold(float3x3::identity(), |a, b| a * b);
+  }
+}
+
+impl<'a> std::iter::Product<&'a float3x3> for float3x3 {
+  #[inline]
+  fn product<I: Iterator<Item=&'a float3x3>>(iter: I) -> float3x3 {
+    return iter.fold(float3x3::identity(), |a, b| a * *b);
+  }
+}
+
+impl Dot<float2x3> for float3x3 {
+  type DotProduct = float2x3;
+
+  #[inline(always)]
+  fn dot(self, other: float2x3) -> Self::DotProduct {
+    return float2x3(self.dot(other.0), self.dot(other.1));
+  }
+}
+
 impl Dot<float3x3> for float3x3 {
   type DotProduct = float3x3;
 
@@ -61,6 +181,15 @@ impl Dot<float3x3> for float3x3 {
   }
 }
 
+impl Dot<float4x3> for float3x3 {
+  type DotProduct = float4x3;
+
+  #[inline(always)]
+  fn dot(self, other: float4x3) -> Self::DotProduct {
+    return float4x3(self.dot(other.0), self.dot(other.1), self.dot(other.2), self.dot(other.3));
+  }
+}
+
 impl Dot<float3> for float3x3 {
   type DotProduct = float3;
 
@@ -77,6 +206,137 @@ impl PartialEq for float3x3 {
   }
 }
 
+impl std::ops::Index<usize> for float3x3 {
+  type Output = float3;
+
+  #[inline]
+  fn index(&self, index: usize) -> &float3 {
+    match index {
+      0 => return &self.0,
+      1 => return &self.1,
+      2 => return &self.2,
+      _ => panic!("column index {} out of range for float3x3", index)
+    }
+  }
+}
+
+impl std::ops::IndexMut<usize> for float3x3 {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut float3 {
+    match index {
+      0 => return &mut self.0,
+      1 => return &mut self.1,
+      2 => return &mut self.2,
+      _ => panic!("column index {} out of range for float3x3", index)
+    }
+  }
+}
+
+impl std::ops::Index<(usize, usize)> for float3x3 {
+  type Output = f32;
+
+  #[inline(always)]
+  fn index(&self, index: (usize, usize)) -> &f32 {
+    return &self[index.1][index.0];
+  }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for float3x3 {
+  #[inline(always)]
+  fn index_mut(&mut self, index: (usize, usize)) -> &mut f32 {
+    return &mut self[index.1][index.0];
+  }
+}
+
+impl From<[[f32; 3]; 3]> for float3x3 {
+  #[inline(always)]
+  fn from(x: [[f32; 3]; 3]) -> float3x3 {
+    return float3x3(float3::from(x[0]), float3::from(x[1]), float3::from(x[2]));
+  }
+}
+
+impl From<float3x3> for [[f32; 3]; 3] {
+  #[inline(always)]
+  fn from(x: float3x3) -> [[f32; 3]; 3] {
+    return [x.0.into(), x.1.into(), x.2.into()];
+  }
+}
+
+impl From<double3x3> for float3x3 {
+  #[inline(always)]
+  fn from(x: double3x3) -> float3x3 {
+    return x.to_float();
+  }
+}
+
+impl Determinant for float3x3 {
+  type Scalar = f32;
+
+  #[inline(always)]
+  fn determinant(self) -> f32 {
+    return dot(self.0, cross(self.1, self.2));
+  }
+}
+
+impl Matrix for float3x3 {
+  type Scalar = f32;
+  type Column = float3;
+  type Row = float3;
+  type Transpose = float3x3;
+  type FloatMatrix = float3x3;
+  type DoubleMatrix = double3x3;
+
+  const ROWS: usize = 3;
+  const COLUMNS: usize = 3;
+
+  #[inline(always)]
+  fn column(self, index: usize) -> float3 {
+    return self[index];
+  }
+
+  #[inline(always)]
+  fn row(self, index: usize) -> float3 {
+    return float3(self.0[index], self.1[index], self.2[index]);
+  }
+
+  #[inline(always)]
+  fn map(self, f: &Fn(f32) -> f32) -> float3x3 {
+    return float3x3(self.0.map_unary(f), self.1.map_unary(f), self.2.map_unary(f));
+  }
+
+  #[inline(always)]
+  fn transpose(self) -> float3x3 {
+    return float3x3::transpose(self);
+  }
+
+  #[inline(always)]
+  fn to_float(self) -> float3x3 {
+    return float3x3(self.0.to_float(), self.1.to_float(), self.2.to_float());
+  }
+
+  #[inline(always)]
+  fn to_double(self) -> double3x3 {
+    return double3x3(self.0.to_double(), self.1.to_double(), self.2.to_double());
+  }
+}
+
+impl SquareMatrix for float3x3 {
+  #[inline(always)]
+  fn identity() -> float3x3 {
+    return float3x3::identity();
+  }
+
+  #[inline(always)]
+  fn inverse(self) -> float3x3 {
+    return float3x3::inverse(self);
+  }
+
+  #[inline(always)]
+  fn trace(self) -> f32 {
+    return float3x3::trace(self);
+  }
+}
+
 impl float3x3 {
   #[inline(always)]
   pub fn from_columns(c0: float3, c1: float3, c2: float3) -> float3x3 {
@@ -88,11 +348,28 @@ impl float3x3 {
     return float3x3(r0, r1, r2).transpose();
   }
 
+  #[inline(always)]
+  pub fn from_row_major(x: [[f32; 3]; 3]) -> float3x3 {
+    return float3x3::from_rows(float3::from(x[0]), float3::from(x[1]), float3::from(x[2]));
+  }
+
+  #[inline(always)]
+  pub fn to_row_major(self) -> [[f32; 3]; 3] {
+    let t = self.transpose();
+
+    return [t.0.into(), t.1.into(), t.2.into()];
+  }
+
   #[inline(always)]
   pub fn identity() -> float3x3 {
     return float3x3(float3(1.0, 0.0, 0.0), float3(0.0, 1.0, 0.0), float3(0.0, 0.0, 1.0));
   }
 
+  #[inline(always)]
+  pub fn from_diagonal(d: float3) -> float3x3 {
+    return float3x3(float3(d.0, 0.0, 0.0), float3(0.0, d.1, 0.0), float3(0.0, 0.0, d.2));
+  }
+
   #[inline(always)]
   pub fn linear_combination(a: f32, x: float3x3, b: f32, y: float3x3) -> float3x3 {
     let a = float3::broadcast(a);
@@ -100,6 +377,11 @@ impl float3x3 {
     return float3x3(a * x.0 + b * y.0, a * x.1 + b * y.1, a * x.2 + b * y.2);
   }
 
+  #[inline(always)]
+  pub fn hadamard(self, other: float3x3) -> float3x3 {
+    return float3x3(self.0 * other.0, self.1 * other.1, self.2 * other.2);
+  }
+
   #[inline(always)]
   pub fn transpose(self) -> float3x3 {
     let c0 = float3((self.0).0, (self.1).0, (self.2).0);
@@ -109,8 +391,102 @@ impl float3x3 {
     return float3x3(c0, c1, c2);
   }
 
+  #[inline(always)]
+  pub fn diagonal(self) -> float3 {
+    return float3((self.0).0, (self.1).1, (self.2).2);
+  }
+
+  #[inline(always)]
+  pub fn trace(self) -> f32 {
+    return (self.0).0 + (self.1).1 + (self.2).2;
+  }
+
+  #[inline(always)]
+  pub fn upper_left_2x2(self) -> float2x2 {
+    return float2x2(float2((self.0).0, (self.0).1), float2((self.1).0, (self.1).1));
+  }
+
+  #[inline(always)]
+  pub fn to_homogeneous(self) -> float4x4 {
+    return float4x4(float4((self.0).0, (self.0).1, (self.0).2, 0.0), float4((self.1).0, (self.1).1, (self.1).2, 0.0), float4((self.2).0, (self.2).1, (self.2).2, 0.0), float4(0.0, 0.0, 0.0, 1.0));
+  }
+
+  #[inline(always)]
+  pub fn to_affine(self) -> float3x2 {
+    return float3x2(float2((self.0).0, (self.0).1), float2((self.1).0, (self.1).1), float2((self.2).0, (self.2).1));
+  }
+
+  #[inline]
+  pub fn minor(self, row: usize, column: usize) -> float2x2 {
+    assert!(row < 3 && column < 3, "minor ({}, {}) out of range for float3x3", row, column);
+
+    let mut m = float2x2::default();
+
+    for c in 0 .. 2 {
+      for r in 0 .. 2 {
+        m[(r, c)] = self[(r + (r >= row) as usize, c + (c >= column) as usize)];
+      }
+    }
+
+    return m;
+  }
+
+  #[cfg(not(feature = "portable"))]
   #[inline(always)]
   pub fn inverse(self) -> float3x3 {
     return unsafe { __invert_f3(self) };
   }
+
+  #[cfg(feature = "portable")]
+  #[inline(always)]
+  pub fn inverse(self) -> float3x3 {
+    return self.inverse_with_det().0;
+  }
+
+  #[cfg(not(feature = "portable"))]
+  #[inline(always)]
+  pub fn inverse_with_det(self) -> (float3x3, f32) {
+    return (self.inverse(), self.determinant());
+  }
+
+  #[cfg(feature = "portable")]
+  #[inline(always)]
+  pub fn inverse_with_det(self) -> (float3x3, f32) {
+    let r0 = cross(self.1, self.2);
+    let r1 = cross(self.2, self.0);
+    let r2 = cross(self.0, self.1);
+
+    let det = dot(self.0, r0);
+
+    return (float3x3::from_rows(r0, r1, r2) * (1.0 / det), det);
+  }
+
+  #[inline(always)]
+  pub fn try_inverse(self) -> Option<float3x3> {
+    return self.try_inverse_epsilon(0.0);
+  }
+
+  #[inline]
+  pub fn try_inverse_epsilon(self, epsilon: f32) -> Option<float3x3> {
+    let (inverse, det) = self.inverse_with_det();
+
+    if (det > epsilon || det < -epsilon) && (inverse.0.is_finite() & inverse.1.is_finite() & inverse.2.is_finite()).all() {
+      return Some(inverse);
+    }
+
+    return None;
+  }
+
+  #[inline]
+  pub fn condition_number_estimate(self) -> f32 {
+    match self.try_inverse() {
+      Some(inverse) => {
+        let a = self.0.abs() + self.1.abs() + self.2.abs();
+        let b = inverse.0.abs() + inverse.1.abs() + inverse.2.abs();
+
+        return a.reduce_max() * b.reduce_max();
+      },
+      None => return std::f32::INFINITY
+    }
+  }
 }
@@ -19,6 +19,24 @@ impl std::ops::Sub for float4x3 {
   }
 }
 
+impl std::ops::Neg for float4x3 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn neg(self) -> Self {
+    return float4x3(-self.0, -self.1, -self.2, -self.3);
+  }
+}
+
+impl std::ops::Mul<float2x4> for float4x3 {
+  type Output = float2x3;
+
+  #[inline(always)]
+  fn mul(self, other: float2x4) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<float3x4> for float4x3 {
   type Output = float3x3;
 
@@ -28,6 +46,15 @@ impl std::ops::Mul<float3x4> for float4x3 {
   }
 }
 
+impl std::ops::Mul<float4x4> for float4x3 {
+  type Output = float4x3;
+
+  #[inline(always)]
+  fn mul(self, other: float4x4) -> Self::Output {
+    return self.dot(other);
+  }
+}
+
 impl std::ops::Mul<float4> for float4x3 {
   type Output = float3;
 
@@ -48,6 +75,77 @@ impl std::ops::Mul<f32> for float4x3 {
   }
 }
 
+impl std::ops::Div<f32> for float4x3 {
+  type Output = Self;
+
+  #[inline(always)]
+  fn div(self, other: f32) -> Self {
+    let a = float3::broadcast(other);
+
+    return float4x3(self.0 / a, self.1 / a, self.2 / a, self.3 / a);
+  }
+}
+
+impl std::ops::Mul<float4x3> for float3 {
+  type Output = float4;
+
+  #[inline(always)]
+  fn mul(self, other: float4x3) -> Self::Output {
+    return other.transpose().dot(self);
+  }
+}
+
+impl std::ops::AddAssign for float4x3 {
+  #[inline(always)]
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl std::ops::SubAssign for float4x3 {
+  #[inline(always)]
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl std::ops::MulAssign<f32> for float4x3 {
+  #[inline(always)]
+  fn mul_assign(&mut self, other: f32) {
+    *self = *self * other;
+  }
+}
+
+impl std::ops::DivAssign<f32> for float4x3 {
+  #[inline(always)]
+  fn div_assign(&mut self, other: f32) {
+    *self = *self / other;
+  }
+}
+
+impl std::iter::Sum for float4x3 {
+  #[inline]
+  fn sum<I: Iterator<Item=float4x3>>(iter: I) -> float4x3 {
+    return iter.fold(float4x3(float3::from(0), float3::from(0), float3::from(0), float3::from(0)), |a, b| a + b);
+  }
+}
+
+impl<'a> std::iter::Sum<&'a float4x3> for float4x3 {
+  #[inline]
+  fn sum<I: Iterator<Item=&'a float4x3>>(iter: I) -> float4x3 {
+    return iter.fold(float4x3(float3::from(0), float3::from(0), float3::from(0), float3::from(0)), |a, b| a + *b);
+  }
+}
+
+impl Dot<float2x4> for float4x3 {
+  type DotProduct = float2x3;
+
+  #[inline(always)]
+  fn dot(self, other: float2x4) -> Self::DotProduct {
+    return float2x3(self.dot(other.0), self.dot(other.1));
+  }
+}
+
 impl Dot<float3x4> for float4x3 {
   type DotProduct = float3x3;
 
@@ -57,6 +155,15 @@ impl Dot<float3x4> for float4x3 {
   }
 }
 
+impl Dot<float4x4> for float4x3 {
+  type DotProduct = float4x3;
+
+  #[inline(always)]
+  fn dot(self, other: float4x4) -> Self::DotProduct {
+    return float4x3(self.dot(other.0), self.dot(other.1), self.dot(other.2), self.dot(other.3));
+  }
+}
+
 impl Dot<float4> for float4x3 {
   type DotProduct = float3;
 
@@ -73,6 +180,113 @@ impl PartialEq for float4x3 {
   }
 }
 
+impl std::ops::Index<usize> for float4x3 {
+  type Output = float3;
+
+  #[inline]
+  fn index(&self, index: usize) -> &float3 {
+    match index {
+      0 => return &self.0,
+      1 => return &self.1,
+      2 => return &self.2,
+      3 => return &self.3,
+      _ => panic!("column index {} out of range for float4x3", index)
+    }
+  }
+}
+
+impl std::ops::IndexMut<usize> for float4x3 {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut float3 {
+    match index {
+      0 => return &mut self.0,
+      1 => return &mut self.1,
+      2 => return &mut self.2,
+      3 => return &mut self.3,
+      _ => panic!("column index {} out of range for float4x3", index)
+    }
+  }
+}
+
+impl std::ops::Index<(usize, usize)> for float4x3 {
+  type Output = f32;
+
+  #[inline(always)]
+  fn index(&self, index: (usize, usize)) -> &f32 {
+    return &self[index.1][index.0];
+  }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for float4x3 {
+  #[inline(always)]
+  fn index_mut(&mut self, index: (usize, usize)) -> &mut f32 {
+    return &mut self[index.1][index.0];
+  }
+}
+
+impl From<[[f32; 3]; 4]> for float4x3 {
+  #[inline(always)]
+  fn from(x: [[f32; 3]; 4]) -> float4x3 {
+    return float4x3(float3::from(x[0]), float3::from(x[1]), float3::from(x[2]), float3::from(x[3]));
+  }
+}
+
+impl From<float4x3> for [[f32; 3]; 4] {
+  #[inline(always)]
+  fn from(x: float4x3) -> [[f32; 3]; 4] {
+    return [x.0.into(), x.1.into(), x.2.into(), x.3.into()];
+  }
+}
+
+impl From<double4x3> for float4x3 {
+  #[inline(always)]
+  fn from(x: double4x3) -> float4x3 {
+    return x.to_float();
+  }
+}
+
+impl Matrix for float4x3 {
+  type Scalar = f32;
+  type Column = float3;
+  type Row = float4;
+  type Transpose = float3x4;
+  type FloatMatrix = float4x3;
+  type DoubleMatrix = double4x3;
+
+  const ROWS: usize = 3;
+  const COLUMNS: usize = 4;
+
+  #[inline(always)]
+  fn column(self, index: usize) -> float3 {
+    return self[index];
+  }
+
+  #[inline(always)]
+  fn row(self, index: usize) -> float4 {
+    return float4(self.0[index], self.1[index], self.2[index], self.3[index]);
+  }
+
+  #[inline(always)]
+  fn map(self, f: &Fn(f32) -> f32) -> float4x3 {
+    return float4x3(self.0.map_unary(f), self.1.map_unary(f), self.2.map_unary(f), self.3.map_unary(f));
+  }
+
+  #[inline(always)]
+  fn transpose(self) -> float3x4 {
+    return float4x3::transpose(self);
+  }
+
+  #[inline(always)]
+  fn to_float(self) -> float4x3 {
+    return float4x3(self.0.to_float(), self.1.to_float(), self.2.to_float(), self.3.to_float());
+  }
+
+  #[inline(always)]
+  fn to_double(self) -> double4x3 {
+    return double4x3(self.0.to_double(), self.1.to_double(), self.2.to_double(), self.3.to_double());
+  }
+}
+
 impl float4x3 {
   #[inline(always)]
   pub fn from_columns(c0: float3, c1: float3, c2: float3, c3: float3) -> float4x3 {
@@ -84,6 +298,18 @@ impl float4x3 {
     return float3x4(r0, r1, r2).transpose();
   }
 
+  #[inline(always)]
+  pub fn from_row_major(x: [[f32; 4]; 3]) -> float4x3 {
+    return float4x3::from_rows(float4::from(x[0]), float4::from(x[1]), float4::from(x[2]));
+  }
+
+  #[inline(always)]
+  pub fn to_row_major(self) -> [[f32; 4]; 3] {
+    let t = self.transpose();
+
+    return [t.0.into(), t.1.into(), t.2.into()];
+  }
+
   #[inline(always)]
   pub fn linear_combination(a: f32, x: float4x3, b: f32, y: float4x3) -> float4x3 {
     let a = float3::broadcast(a);
@@ -91,6 +317,11 @@ impl float4x3 {
     return float4x3(a * x.0 + b * y.0, a * x.1 + b * y.1, a * x.2 + b * y.2, a * x.3 + b * y.3);
   }
 
+  #[inline(always)]
+  pub fn hadamard(self, other: float4x3) -> float4x3 {
+    return float4x3(self.0 * other.0, self.1 * other.1, self.2 * other.2, self.3 * other.3);
+  }
+
   #[inline(always)]
   pub fn transpose(self) -> float3x4 {
     let c0 = float4((self.0).0, (self.1).0, (self.2).0, (self.3).0);
@@ -99,4 +330,40 @@ impl float4x3 {
 
     return float3x4(c0, c1, c2);
   }
+
+  #[inline(always)]
+  pub fn upper_left_2x2(self) -> float2x2 {
+    return float2x2(float2((self.0).0, (self.0).1), float2((self.1).0, (self.1).1));
+  }
+
+  #[inline(always)]
+  pub fn upper_left_3x3(self) -> float3x3 {
+    return float3x3(self.0, self.1, self.2);
+  }
+
+  #[inline(always)]
+  pub fn to_homogeneous(self) -> float4x4 {
+    return float4x4(float4((self.0).0, (self.0).1, (self.0).2, 0.0), float4((self.1).0, (self.1).1, (self.1).2, 0.0), float4((self.2).0, (self.2).1, (self.2).2, 0.0), float4((self.3).0, (self.3).1, (self.3).2, 1.0));
+  }
+
+  #[inline(always)]
+  pub fn inverse_affine(self) -> float4x3 {
+    let m = float3x3(self.0, self.1, self.2).inverse();
+    let t = -(m * self.3);
+
+    return float4x3(m.0, m.1, m.2, t);
+  }
+
+  #[inline(always)]
+  pub fn inverse_rigid(self) -> float4x3 {
+    let m = float3x3(self.0, self.1, self.2).transpose();
+    let t = -(m * self.3);
+
+    return float4x3(m.0, m.1, m.2, t);
+  }
+
+  #[inline(always)]
+  pub fn normal_matrix(self) -> float3x3 {
+    return float3x3(self.0, self.1, self.2).inverse().transpose();
+  }
 }
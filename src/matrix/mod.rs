@@ -1,4 +1,7 @@
+use std::ops::*;
+
 use ::*;
+use scalar::Lane;
 
 mod matrix_float2x2;
 mod matrix_float3x2;
@@ -27,3 +30,108 @@ declare_matrix!(float2x4, float3x4, float4x4, float4);
 declare_matrix!(double2x2, double3x2, double4x2, double2);
 declare_matrix!(double2x3, double3x3, double4x3, double3);
 declare_matrix!(double2x4, double3x4, double4x4, double4);
+
+/// A matrix of `COLUMNS` column vectors with `ROWS` lanes each. Indexing with a `usize` gives a column and with a
+/// `(row, column)` pair a single element.
+pub trait Matrix : Sized + Copy + Default + PartialEq + Add<Output=Self> + Sub<Output=Self> + Neg<Output=Self> +
+  Mul<<Self as Matrix>::Scalar, Output=Self> + Div<<Self as Matrix>::Scalar, Output=Self> + MulAssign<<Self as Matrix>::Scalar> +
+  DivAssign<<Self as Matrix>::Scalar> + Mul<<Self as Matrix>::Row, Output=<Self as Matrix>::Column> +
+  Index<usize, Output=<Self as Matrix>::Column> + IndexMut<usize> + Index<(usize, usize), Output=<Self as Matrix>::Scalar> +
+  IndexMut<(usize, usize)> {
+  type Scalar: scalar::Scalar;
+  type Column: Vector<Scalar=<Self as Matrix>::Scalar>;
+  type Row: Vector<Scalar=<Self as Matrix>::Scalar>;
+  type Transpose: Matrix<Scalar=<Self as Matrix>::Scalar, Column=<Self as Matrix>::Row, Row=<Self as Matrix>::Column>;
+
+  type FloatMatrix: Matrix<Scalar=f32>;
+  type DoubleMatrix: Matrix<Scalar=f64>;
+
+  const ROWS: usize;
+  const COLUMNS: usize;
+
+  fn column(self, index: usize) -> Self::Column;
+  fn row(self, index: usize) -> Self::Row;
+
+  #[inline(always)]
+  fn set_column(&mut self, index: usize, column: Self::Column) {
+    self[index] = column;
+  }
+
+  #[inline]
+  fn set_row(&mut self, index: usize, row: Self::Row) {
+    for k in 0 .. Self::COLUMNS {
+      self[k][index] = row[k];
+    }
+  }
+
+  #[inline]
+  fn swap_columns(&mut self, a: usize, b: usize) {
+    let (x, y) = (self.column(a), self.column(b));
+
+    self.set_column(a, y);
+    self.set_column(b, x);
+  }
+
+  #[inline]
+  fn swap_rows(&mut self, a: usize, b: usize) {
+    let (x, y) = (self.row(a), self.row(b));
+
+    self.set_row(a, y);
+    self.set_row(b, x);
+  }
+
+  /// Applies `f` to every element.
+  fn map(self, f: &Fn(<Self as Matrix>::Scalar) -> <Self as Matrix>::Scalar) -> Self;
+
+  fn transpose(self) -> Self::Transpose;
+
+  fn to_float(self) -> Self::FloatMatrix;
+  fn to_double(self) -> Self::DoubleMatrix;
+
+  /// Copies the overlapping elements into a matrix of another size, the elements outside of this one are taken from
+  /// the identity.
+  #[inline]
+  fn resize<T: Matrix<Scalar=<Self as Matrix>::Scalar>>(self) -> T {
+    let mut x = T::default();
+
+    for c in 0 .. T::COLUMNS {
+      for r in 0 .. T::ROWS {
+        x[(r, c)] = if r < Self::ROWS && c < Self::COLUMNS { self[(r, c)] } else { Lane::from_u64((r == c) as u64) };
+      }
+    }
+
+    return x;
+  }
+}
+
+pub trait SquareMatrix : Matrix<Transpose=Self> + Mul<Output=Self> + Determinant<Scalar=<Self as Matrix>::Scalar> {
+  fn identity() -> Self;
+  fn inverse(self) -> Self;
+  fn trace(self) -> <Self as Matrix>::Scalar;
+}
+
+#[inline(always)]
+pub fn transpose<T: Matrix>(x: T) -> T::Transpose {
+  return x.transpose();
+}
+
+#[inline(always)]
+pub fn inverse<T: SquareMatrix>(x: T) -> T {
+  return x.inverse();
+}
+
+#[inline(always)]
+pub fn trace<T: SquareMatrix>(x: T) -> <T as Matrix>::Scalar {
+  return x.trace();
+}
+
+pub trait Determinant {
+  type Scalar;
+
+  fn determinant(self) -> Self::Scalar;
+}
+
+#[inline(always)]
+pub fn determinant<T: Determinant>(x: T) -> T::Scalar {
+  return x.determinant();
+}
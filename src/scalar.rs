@@ -1,11 +1,34 @@
 use std;
 use std::ops::*;
 
-pub trait Scalar : Sized + Copy + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + PartialEq {
+pub trait Scalar : Lane + Sized + Copy + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + PartialEq {
   fn max(x: Self, y: Self) -> Self;
   fn min(x: Self, y: Self) -> Self;
 }
 
+// Lane level operations used by the portable backend, kept separate so that they don't show up on the public traits
+#[doc(hidden)]
+pub trait Lane : Copy + PartialEq + PartialOrd {
+  fn mask(x: bool) -> Self;
+  fn cast<T: Lane>(self) -> T;
+
+  fn from_i64(x: i64) -> Self;
+  fn from_u64(x: u64) -> Self;
+  fn from_f64(x: f64) -> Self;
+
+  fn lane_add(x: Self, y: Self) -> Self;
+  fn lane_sub(x: Self, y: Self) -> Self;
+  fn lane_mul(x: Self, y: Self) -> Self;
+  fn lane_div(x: Self, y: Self) -> Self;
+
+  fn lane_shl(x: Self, y: Self) -> Self;
+  fn lane_shr(x: Self, y: Self) -> Self;
+
+  fn lane_and(x: Self, y: Self) -> Self;
+  fn lane_or(x: Self, y: Self) -> Self;
+  fn lane_xor(x: Self, y: Self) -> Self;
+}
+
 pub trait FloatScalar : Scalar {
   fn sqrt(x: Self) -> Self;
 
@@ -16,6 +39,11 @@ pub trait FloatScalar : Scalar {
 
   fn sin(x: Self) -> Self;
   fn cos(x: Self) -> Self;
+  fn tan(x: Self) -> Self;
+  fn sin_cos(x: Self) -> (Self, Self);
+
+  /// `x * y + z` with a single rounding.
+  fn fma(x: Self, y: Self, z: Self) -> Self;
 }
 
 pub trait IntegerScalar : Scalar + BitAnd<Output=Self> + BitOr<Output=Self> + BitXor<Output=Self> + PartialEq {
@@ -0,0 +1,148 @@
+use std::fmt;
+use std::marker::PhantomData;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::{Error, Expected, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use ::*;
+
+// Vectors are serialized as a tuple of lanes and matrices as a tuple of columns, so both end up as plain fixed length
+// arrays in formats like JSON
+
+struct TupleVisitor<T> {
+  name: &'static str,
+  len: usize,
+  marker: PhantomData<T>
+}
+
+#[inline]
+fn next_element<'de, A: SeqAccess<'de>, T: Deserialize<'de>>(seq: &mut A, i: usize, expected: &Expected) -> Result<T, A::Error> {
+  return match seq.next_element()? {
+    Some(x) => Ok(x),
+    None => Err(A::Error::invalid_length(i, expected))
+  };
+}
+
+#[inline]
+fn end<'de, A: SeqAccess<'de>>(seq: &mut A, len: usize, expected: &Expected) -> Result<(), A::Error> {
+  let mut n = len;
+
+  while let Some(IgnoredAny) = seq.next_element()? {
+    n += 1;
+  }
+
+  return if n == len { Ok(()) } else { Err(A::Error::invalid_length(n, expected)) };
+}
+
+macro_rules! impl_serde {
+  ($name:ident, $len:expr, $($i:tt),+) => {
+    impl Serialize for $name {
+      fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut t = serializer.serialize_tuple($len)?;
+        $(t.serialize_element(&self.$i)?;)+
+        return t.end();
+      }
+    }
+
+    impl<'de> Visitor<'de> for TupleVisitor<$name> {
+      type Value = $name;
+
+      fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{} as a sequence of {} elements", self.name, self.len);
+      }
+
+      fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<$name, A::Error> {
+        let x = $name($(next_element(&mut seq, $i, &self)?),+);
+
+        end(&mut seq, $len, &self)?;
+
+        return Ok(x);
+      }
+    }
+
+    impl<'de> Deserialize<'de> for $name {
+      fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$name, D::Error> {
+        return deserializer.deserialize_tuple($len, TupleVisitor::<$name> { name: stringify!($name), len: $len, marker: PhantomData });
+      }
+    }
+  }
+}
+
+impl_serde!(char2, 2, 0, 1);
+impl_serde!(char3, 3, 0, 1, 2);
+impl_serde!(char4, 4, 0, 1, 2, 3);
+impl_serde!(char8, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_serde!(char16, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+impl_serde!(uchar2, 2, 0, 1);
+impl_serde!(uchar3, 3, 0, 1, 2);
+impl_serde!(uchar4, 4, 0, 1, 2, 3);
+impl_serde!(uchar8, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_serde!(uchar16, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+impl_serde!(short2, 2, 0, 1);
+impl_serde!(short3, 3, 0, 1, 2);
+impl_serde!(short4, 4, 0, 1, 2, 3);
+impl_serde!(short8, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_serde!(short16, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+impl_serde!(ushort2, 2, 0, 1);
+impl_serde!(ushort3, 3, 0, 1, 2);
+impl_serde!(ushort4, 4, 0, 1, 2, 3);
+impl_serde!(ushort8, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_serde!(ushort16, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+impl_serde!(int2, 2, 0, 1);
+impl_serde!(int3, 3, 0, 1, 2);
+impl_serde!(int4, 4, 0, 1, 2, 3);
+impl_serde!(int8, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_serde!(int16, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+impl_serde!(uint2, 2, 0, 1);
+impl_serde!(uint3, 3, 0, 1, 2);
+impl_serde!(uint4, 4, 0, 1, 2, 3);
+impl_serde!(uint8, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_serde!(uint16, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+impl_serde!(long2, 2, 0, 1);
+impl_serde!(long3, 3, 0, 1, 2);
+impl_serde!(long4, 4, 0, 1, 2, 3);
+impl_serde!(long8, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_serde!(long16, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+impl_serde!(ulong2, 2, 0, 1);
+impl_serde!(ulong3, 3, 0, 1, 2);
+impl_serde!(ulong4, 4, 0, 1, 2, 3);
+impl_serde!(ulong8, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_serde!(ulong16, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+impl_serde!(float2, 2, 0, 1);
+impl_serde!(float3, 3, 0, 1, 2);
+impl_serde!(float4, 4, 0, 1, 2, 3);
+impl_serde!(float8, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_serde!(float16, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+impl_serde!(double2, 2, 0, 1);
+impl_serde!(double3, 3, 0, 1, 2);
+impl_serde!(double4, 4, 0, 1, 2, 3);
+impl_serde!(double8, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_serde!(double16, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+impl_serde!(float2x2, 2, 0, 1);
+impl_serde!(float2x3, 2, 0, 1);
+impl_serde!(float2x4, 2, 0, 1);
+impl_serde!(float3x2, 3, 0, 1, 2);
+impl_serde!(float3x3, 3, 0, 1, 2);
+impl_serde!(float3x4, 3, 0, 1, 2);
+impl_serde!(float4x2, 4, 0, 1, 2, 3);
+impl_serde!(float4x3, 4, 0, 1, 2, 3);
+impl_serde!(float4x4, 4, 0, 1, 2, 3);
+
+impl_serde!(double2x2, 2, 0, 1);
+impl_serde!(double2x3, 2, 0, 1);
+impl_serde!(double2x4, 2, 0, 1);
+impl_serde!(double3x2, 3, 0, 1, 2);
+impl_serde!(double3x3, 3, 0, 1, 2);
+impl_serde!(double3x4, 3, 0, 1, 2);
+impl_serde!(double4x2, 4, 0, 1, 2, 3);
+impl_serde!(double4x3, 4, 0, 1, 2, 3);
+impl_serde!(double4x4, 4, 0, 1, 2, 3);
@@ -0,0 +1,92 @@
+use ::*;
+
+/// Iterator over the lanes of a vector, in order from lane 0.
+#[derive(Copy, Clone, Debug)]
+pub struct Lanes<T> {
+  vector: T,
+  front: usize,
+  back: usize
+}
+
+impl<T: Vector> Lanes<T> {
+  #[inline(always)]
+  pub fn new(x: T) -> Lanes<T> {
+    return Lanes { vector: x, front: 0, back: T::LANES };
+  }
+}
+
+impl<T: Vector> Iterator for Lanes<T> {
+  type Item = T::Scalar;
+
+  #[inline(always)]
+  fn next(&mut self) -> Option<T::Scalar> {
+    if self.front == self.back {
+      return None;
+    }
+
+    self.front += 1;
+
+    return Some(self.vector[self.front - 1]);
+  }
+
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    return (self.back - self.front, Some(self.back - self.front));
+  }
+}
+
+impl<T: Vector> DoubleEndedIterator for Lanes<T> {
+  #[inline(always)]
+  fn next_back(&mut self) -> Option<T::Scalar> {
+    if self.front == self.back {
+      return None;
+    }
+
+    self.back -= 1;
+
+    return Some(self.vector[self.back]);
+  }
+}
+
+impl<T: Vector> ExactSizeIterator for Lanes<T> {
+}
+
+/// Iterator over the indices of the set lanes of a mask, from the lowest lane up.
+#[derive(Copy, Clone, Debug)]
+pub struct SetLanes {
+  bits: u16
+}
+
+impl SetLanes {
+  #[inline(always)]
+  pub fn new(bits: u16) -> SetLanes {
+    return SetLanes { bits: bits };
+  }
+}
+
+impl Iterator for SetLanes {
+  type Item = usize;
+
+  #[inline(always)]
+  fn next(&mut self) -> Option<usize> {
+    if self.bits == 0 {
+      return None;
+    }
+
+    let i = self.bits.trailing_zeros() as usize;
+
+    self.bits &= self.bits - 1;
+
+    return Some(i);
+  }
+
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let n = self.bits.count_ones() as usize;
+
+    return (n, Some(n));
+  }
+}
+
+impl ExactSizeIterator for SetLanes {
+}
@@ -64,6 +64,21 @@ mod vector_double4;
 mod vector_double8;
 mod vector_double16;
 
+mod lanes;
+mod ordering;
+mod shuffle;
+
+pub use self::lanes::*;
+pub use self::ordering::*;
+pub use self::shuffle::*;
+
+#[cfg(feature = "portable")]
+mod portable;
+
+#[cfg(feature = "portable")]
+use self::portable::*;
+
+#[cfg(not(feature = "portable"))]
 extern "platform-intrinsic" {
   fn simd_add<T>(x: T, y: T) -> T;
   fn simd_sub<T>(x: T, y: T) -> T;
@@ -90,42 +105,68 @@ extern "platform-intrinsic" {
   fn simd_extract<T, E>(x: T, i: u32) -> E;
 }
 
-declare_vector!(char2, char3, char4, char8, char16, i8, signed);
-declare_vector!(short2, short3, short4, short8, short16, i16, signed);
-declare_vector!(int2, int3, int4, int8, int16, i32, signed);
-declare_vector!(long2, long3, long4, long8, long16, i64, signed);
+declare_vector!(char2, char3, char4, char8, char16, i8, signed, 2, 4, 4, 8, 16);
+declare_vector!(short2, short3, short4, short8, short16, i16, signed, 4, 8, 8, 16, 32);
+declare_vector!(int2, int3, int4, int8, int16, i32, signed, 8, 16, 16, 32, 64);
+declare_vector!(long2, long3, long4, long8, long16, i64, signed, 16, 32, 32, 64, 128);
 
-declare_vector!(uchar2, uchar3, uchar4, uchar8, uchar16, u8, unsigned);
-declare_vector!(ushort2, ushort3, ushort4, ushort8, ushort16, u16, unsigned);
-declare_vector!(uint2, uint3, uint4, uint8, uint16, u32, unsigned);
-declare_vector!(ulong2, ulong3, ulong4, ulong8, ulong16, u64, unsigned);
+declare_vector!(uchar2, uchar3, uchar4, uchar8, uchar16, u8, unsigned, 2, 4, 4, 8, 16);
+declare_vector!(ushort2, ushort3, ushort4, ushort8, ushort16, u16, unsigned, 4, 8, 8, 16, 32);
+declare_vector!(uint2, uint3, uint4, uint8, uint16, u32, unsigned, 8, 16, 16, 32, 64);
+declare_vector!(ulong2, ulong3, ulong4, ulong8, ulong16, u64, unsigned, 16, 32, 32, 64, 128);
 
 // TODO: declare_vector!(half2, half3, half4, f16, float);
-declare_vector!(float2, float3, float4, float8, float16, f32, float);
-declare_vector!(double2, double3, double4, double8, double16, f64, float);
+declare_vector!(float2, float3, float4, float8, float16, f32, float, 8, 16, 16, 32, 64);
+declare_vector!(double2, double3, double4, double8, double16, f64, float, 16, 32, 32, 64, 128);
 
-pub trait Vector : Sized + Copy + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + Dot<Self, DotProduct=<Self as Vector>::Scalar> + From<isize> {
+pub trait Vector : Sized + Copy + Default + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + Dot<Self, DotProduct=<Self as Vector>::Scalar> + From<isize> +
+  Index<usize, Output=<Self as Vector>::Scalar> + IndexMut<usize> + AsRef<[<Self as Vector>::Scalar]> + AsMut<[<Self as Vector>::Scalar]> +
+  IntoIterator<Item=<Self as Vector>::Scalar, IntoIter=Lanes<Self>> + std::iter::FromIterator<<Self as Vector>::Scalar> + Shuffle {
   type Scalar: scalar::Scalar + Into<Self>;
-  type Boolean: Select<Self> + Vector;
+  type Boolean: Select<Self> + Mask + Vector;
+
+  /// The number of lanes, a 3 lane vector still takes up the size of a 4 lane one
+  const LANES: usize;
 
-  type CharVector;
-  type ShortVector;
-  type IntVector;
-  type LongVector;
+  type CharVector: Vector;
+  type ShortVector: Vector;
+  type IntVector: Vector;
+  type LongVector: Vector;
 
-  type UCharVector;
-  type UShortVector;
-  type UIntVector;
-  type ULongVector;
+  type UCharVector: Vector;
+  type UShortVector: Vector;
+  type UIntVector: Vector;
+  type ULongVector: Vector;
 
-  type FloatVector;
-  type DoubleVector;
+  type FloatVector: Vector;
+  type DoubleVector: Vector;
 
   fn map_unary(self, f: &Fn(Self::Scalar) -> Self::Scalar) -> Self;
   fn map_binary(self, other: Self, f: &Fn(Self::Scalar, Self::Scalar) -> Self::Scalar) -> Self;
 
   fn reduce(self, f: &Fn(Self::Scalar, Self::Scalar) -> Self::Scalar) -> Self::Scalar;
 
+  /// Like `reduce` but combines the lanes as a balanced tree, every step pairs lane `i` with lane `i ^ n` through
+  /// `swap_lanes` and halves `n` until one lane is left. For float sums the rounding error grows with the depth of the
+  /// tree instead of with the number of lanes.
+  #[inline(always)]
+  fn reduce_tree(self, f: &Fn(Self::Scalar, Self::Scalar) -> Self::Scalar) -> Self::Scalar {
+    // Three lanes don't split evenly, the middle lane is added to the pair of the outer ones
+    if Self::LANES == 3 {
+      return f(f(self[0], self[2]), self[1]);
+    }
+
+    let mut x = self;
+    let mut n = Self::LANES / 2;
+
+    while n > 0 {
+      x = x.map_binary(x.swap_lanes(n), f);
+      n /= 2;
+    }
+
+    return x[0];
+  }
+
   #[inline(always)]
   fn bitcast<T: Vector>(x: T) -> Self {
     assert_eq!(std::mem::size_of::<T>(), std::mem::size_of::<Self>());
@@ -205,6 +246,11 @@ pub trait Vector : Sized + Copy + Add<Output=Self> + Sub<Output=Self> + Mul<Outp
     return self.reduce(&Self::Scalar::add);
   }
 
+  #[inline(always)]
+  fn reduce_mul(self) -> Self::Scalar {
+    return self.reduce(&Self::Scalar::mul);
+  }
+
   #[inline(always)]
   fn reduce_max(self) -> Self::Scalar {
     return self.reduce(&Self::Scalar::max);
@@ -215,6 +261,52 @@ pub trait Vector : Sized + Copy + Add<Output=Self> + Sub<Output=Self> + Mul<Outp
     return self.reduce(&Self::Scalar::min);
   }
 
+  /// Inclusive prefix sum, lane `i` of the result is the sum of lanes `0 ..= i`.
+  #[inline(always)]
+  fn scan_add(self) -> Self {
+    let mut x = self;
+    let mut n = 1;
+
+    while n < Self::LANES {
+      x = x + x.shift_lanes_up(n, Self::from(0));
+      n *= 2;
+    }
+
+    return x;
+  }
+
+  /// Exclusive prefix sum, lane `i` of the result is the sum of lanes `0 .. i`.
+  #[inline(always)]
+  fn scan_add_exclusive(self) -> Self {
+    return self.scan_add().shift_lanes_up(1, Self::from(0));
+  }
+
+  /// Inclusive prefix maximum, lane `i` of the result is the maximum of lanes `0 ..= i`.
+  #[inline(always)]
+  fn scan_max(self) -> Self {
+    let mut x = self;
+    let mut n = 1;
+
+    while n < Self::LANES {
+      x = x.max(x.shift_lanes_up(n, x));
+      n *= 2;
+    }
+
+    return x;
+  }
+
+  /// Index of the first lane holding the smallest value, NaN lanes are skipped like in `reduce_min`.
+  #[inline(always)]
+  fn argmin(self) -> usize {
+    return self.eq(Self::broadcast(self.reduce_min())).first_set().unwrap_or(0);
+  }
+
+  /// Index of the first lane holding the largest value, NaN lanes are skipped like in `reduce_max`.
+  #[inline(always)]
+  fn argmax(self) -> usize {
+    return self.eq(Self::broadcast(self.reduce_max())).first_set().unwrap_or(0);
+  }
+
   #[inline(always)]
   fn to_char(self) -> Self::CharVector {
     return unsafe { simd_cast(self) };
@@ -351,6 +443,36 @@ pub fn reduce_add<T: Vector>(x: T) -> T::Scalar {
   return x.reduce_add();
 }
 
+#[inline(always)]
+pub fn reduce_mul<T: Vector>(x: T) -> T::Scalar {
+  return x.reduce_mul();
+}
+
+#[inline(always)]
+pub fn scan_add<T: Vector>(x: T) -> T {
+  return x.scan_add();
+}
+
+#[inline(always)]
+pub fn scan_add_exclusive<T: Vector>(x: T) -> T {
+  return x.scan_add_exclusive();
+}
+
+#[inline(always)]
+pub fn scan_max<T: Vector>(x: T) -> T {
+  return x.scan_max();
+}
+
+#[inline(always)]
+pub fn argmin<T: Vector>(x: T) -> usize {
+  return x.argmin();
+}
+
+#[inline(always)]
+pub fn argmax<T: Vector>(x: T) -> usize {
+  return x.argmax();
+}
+
 #[inline(always)]
 pub fn reduce_max<T: Vector>(x: T) -> T::Scalar {
   return x.reduce_max();
@@ -471,6 +593,118 @@ pub trait Float : Vector<Scalar=<Self as Float>::FloatScalar> {
   type FloatScalar: scalar::FloatScalar + Into<Self>;
 
   const SIGN_MASK: <<Self as Vector>::Boolean as Vector>::Scalar;
+  const EXPONENT_MASK: <<Self as Vector>::Boolean as Vector>::Scalar;
+
+  #[inline(always)]
+  fn is_nan(self) -> Self::Boolean {
+    let exponent = Self::Boolean::broadcast(Self::EXPONENT_MASK);
+
+    return Self::Boolean::bitcast(magnitude(self).gt(exponent));
+  }
+
+  #[inline(always)]
+  fn is_infinite(self) -> Self::Boolean {
+    let exponent = Self::Boolean::broadcast(Self::EXPONENT_MASK);
+
+    return Self::Boolean::bitcast(magnitude(self).eq(exponent));
+  }
+
+  #[inline(always)]
+  fn is_finite(self) -> Self::Boolean {
+    let exponent = Self::Boolean::broadcast(Self::EXPONENT_MASK);
+
+    return Self::Boolean::bitcast(magnitude(self).lt(exponent));
+  }
+
+  /// True for lanes that are neither zero, subnormal, infinite nor NaN.
+  #[inline(always)]
+  fn is_normal(self) -> Self::Boolean {
+    let exponent = Self::Boolean::broadcast(Self::EXPONENT_MASK);
+    let e = self.to_boolean() & exponent;
+
+    return Self::Boolean::bitcast(e.ne(Self::Boolean::from(0))) & Self::Boolean::bitcast(e.ne(exponent));
+  }
+
+  #[inline(always)]
+  fn is_subnormal(self) -> Self::Boolean {
+    let exponent = Self::Boolean::broadcast(Self::EXPONENT_MASK);
+    let e = self.to_boolean() & exponent;
+
+    return Self::Boolean::bitcast(e.eq(Self::Boolean::from(0))) & Self::Boolean::bitcast(magnitude(self).ne(Self::Boolean::from(0)));
+  }
+
+  /// True for lanes with the sign bit set, including `-0.0` and NaNs with the sign bit set.
+  #[inline(always)]
+  fn signbit(self) -> Self::Boolean {
+    let bits = self.to_boolean();
+
+    return Self::Boolean::bitcast(bits.ne(magnitude(self)));
+  }
+
+  /// Replaces the NaN lanes with `replacement`, infinite lanes are kept.
+  #[inline(always)]
+  fn nan_to_num(self, replacement: Self::Scalar) -> Self {
+    return self.is_nan().select(self, Self::broadcast(replacement));
+  }
+
+  /// Lane-wise maximum that returns NaN if either lane is NaN and treats `-0.0` as less than `0.0`.
+  #[inline(always)]
+  fn maximum(self, other: Self) -> Self {
+    let r = signed_zero_max(self, other, self.gt(other).select(other, self));
+
+    return (self.ne(self) | other.ne(other)).select(r, self + other);
+  }
+
+  /// Lane-wise minimum that returns NaN if either lane is NaN and treats `-0.0` as less than `0.0`.
+  #[inline(always)]
+  fn minimum(self, other: Self) -> Self {
+    let r = signed_zero_min(self, other, self.lt(other).select(other, self));
+
+    return (self.ne(self) | other.ne(other)).select(r, self + other);
+  }
+
+  /// Lane-wise maximum that returns the other lane if one of them is NaN and treats `-0.0` as less than `0.0`.
+  #[inline(always)]
+  fn maxnum(self, other: Self) -> Self {
+    return signed_zero_max(self, other, (self.gt(other) | other.ne(other)).select(other, self));
+  }
+
+  /// Lane-wise minimum that returns the other lane if one of them is NaN and treats `-0.0` as less than `0.0`.
+  #[inline(always)]
+  fn minnum(self, other: Self) -> Self {
+    return signed_zero_min(self, other, (self.lt(other) | other.ne(other)).select(other, self));
+  }
+
+  /// The lane with the larger magnitude, `maxnum` if the magnitudes are equal.
+  #[inline(always)]
+  fn maxmag(self, other: Self) -> Self {
+    let (x, y) = (self.abs(), other.abs());
+
+    return y.gt(x).select(x.gt(y).select(self.maxnum(other), self), other);
+  }
+
+  /// The lane with the smaller magnitude, `minnum` if the magnitudes are equal.
+  #[inline(always)]
+  fn minmag(self, other: Self) -> Self {
+    let (x, y) = (self.abs(), other.abs());
+
+    return y.lt(x).select(x.lt(y).select(self.minnum(other), self), other);
+  }
+
+  /// `x - y` where `x > y` and `+0.0` otherwise, NaN if either lane is NaN.
+  #[inline(always)]
+  fn fdim(self, other: Self) -> Self {
+    let r = self.gt(other).select(Self::from(0), self - other);
+
+    return (self.ne(self) | other.ne(other)).select(r, self + other);
+  }
+
+  /// `self * b + c` rounded once, unlike `add_mul` this is never split into a multiplication and an addition. Without
+  /// hardware FMA the lanes are computed in software with the exact product.
+  #[inline(always)]
+  fn fma(self, b: Self, c: Self) -> Self {
+    return fma_lanes(self, b, c);
+  }
 
   #[inline(always)]
   fn copysign(self, magnitude: Self) -> Self {
@@ -487,16 +721,55 @@ pub trait Float : Vector<Scalar=<Self as Float>::FloatScalar> {
     return self.map_unary(&Self::Scalar::sqrt);
   }
 
+  /// Correctly rounded `1 / x`.
   #[inline(always)]
   fn recip(self) -> Self {
     return Self::from(1) / self;
   }
 
+  /// The hardware reciprocal estimate as is, within 4996 ULP on SSE and AVX and within 710 ULP on AVX-512 as long as
+  /// the result is a normal number. Falls back to `recip` where there is no estimate instruction.
+  #[inline(always)]
+  fn recip_fast(self) -> Self {
+    return self.recip();
+  }
+
+  /// `recip_fast` refined with one Newton-Raphson step, within 3 ULP on SSE and AVX and within 2 ULP on AVX-512.
+  /// Infinite lanes come out as NaN. Falls back to `recip` where there is no estimate instruction.
+  #[inline(always)]
+  fn recip_nr1(self) -> Self {
+    return self.recip();
+  }
+
+  /// `1 / sqrt(x)`. On x86 `float4`, `float8` and `float16` this is `rsqrt_nr1` where the estimate instruction is
+  /// available and `double8` refines the AVX-512 estimate with two Newton-Raphson steps to within 3 ULP, otherwise a
+  /// rounded square root and a rounded division within 1 ULP.
   #[inline(always)]
   fn rsqrt(self) -> Self {
     return self.sqrt().recip();
   }
 
+  /// `1 / sqrt(x)` with a rounded square root and a rounded division, within 1 ULP on every target and with every set
+  /// of CPU features. For code that can't live with the error of the estimate behind `rsqrt`.
+  #[inline(always)]
+  fn rsqrt_accurate(self) -> Self {
+    return self.sqrt().recip();
+  }
+
+  /// The hardware reciprocal square root estimate as is, within 4980 ULP on SSE and AVX and within 938 ULP on AVX-512.
+  /// Falls back to `rsqrt` where there is no estimate instruction.
+  #[inline(always)]
+  fn rsqrt_fast(self) -> Self {
+    return self.rsqrt();
+  }
+
+  /// `rsqrt_fast` refined with one Newton-Raphson step, within 5 ULP on SSE and AVX and within 3 ULP on AVX-512.
+  /// Infinite lanes come out as NaN. Falls back to `rsqrt` where there is no estimate instruction.
+  #[inline(always)]
+  fn rsqrt_nr1(self) -> Self {
+    return self.rsqrt();
+  }
+
   #[inline(always)]
   fn fract(self) -> Self {
     return self.map_unary(&Self::Scalar::fract);
@@ -545,6 +818,109 @@ pub trait Float : Vector<Scalar=<Self as Float>::FloatScalar> {
   }
 }
 
+// The bits of |x|, which compare like the numbers for everything but NaN
+#[inline(always)]
+fn magnitude<T: Float>(x: T) -> T::Boolean {
+  return x.to_boolean() & T::Boolean::broadcast(T::SIGN_MASK);
+}
+
+// Equal lanes can only differ in the sign of zero, and the bits of 0.0 are the and / the bits of -0.0 the or of both
+#[inline(always)]
+fn signed_zero_max<T: Float>(x: T, y: T, r: T) -> T {
+  return x.eq(y).select(r, T::bitcast(x.to_boolean() & y.to_boolean()));
+}
+
+#[inline(always)]
+fn signed_zero_min<T: Float>(x: T, y: T, r: T) -> T {
+  return x.eq(y).select(r, T::bitcast(x.to_boolean() | y.to_boolean()));
+}
+
+#[inline(always)]
+fn fma_lanes<T: Float>(a: T, b: T, c: T) -> T {
+  let mut r = a;
+
+  for i in 0 .. T::LANES {
+    r[i] = T::Scalar::fma(a[i], b[i], c[i]);
+  }
+
+  return r;
+}
+
+#[inline(always)]
+pub fn is_nan<T: Float>(x: T) -> T::Boolean {
+  return x.is_nan();
+}
+
+#[inline(always)]
+pub fn is_infinite<T: Float>(x: T) -> T::Boolean {
+  return x.is_infinite();
+}
+
+#[inline(always)]
+pub fn is_finite<T: Float>(x: T) -> T::Boolean {
+  return x.is_finite();
+}
+
+#[inline(always)]
+pub fn is_normal<T: Float>(x: T) -> T::Boolean {
+  return x.is_normal();
+}
+
+#[inline(always)]
+pub fn is_subnormal<T: Float>(x: T) -> T::Boolean {
+  return x.is_subnormal();
+}
+
+#[inline(always)]
+pub fn signbit<T: Float>(x: T) -> T::Boolean {
+  return x.signbit();
+}
+
+#[inline(always)]
+pub fn nan_to_num<T: Float>(x: T, replacement: T::Scalar) -> T {
+  return x.nan_to_num(replacement);
+}
+
+#[inline(always)]
+pub fn maximum<T: Float>(x: T, y: T) -> T {
+  return x.maximum(y);
+}
+
+#[inline(always)]
+pub fn minimum<T: Float>(x: T, y: T) -> T {
+  return x.minimum(y);
+}
+
+#[inline(always)]
+pub fn maxnum<T: Float>(x: T, y: T) -> T {
+  return x.maxnum(y);
+}
+
+#[inline(always)]
+pub fn minnum<T: Float>(x: T, y: T) -> T {
+  return x.minnum(y);
+}
+
+#[inline(always)]
+pub fn maxmag<T: Float>(x: T, y: T) -> T {
+  return x.maxmag(y);
+}
+
+#[inline(always)]
+pub fn minmag<T: Float>(x: T, y: T) -> T {
+  return x.minmag(y);
+}
+
+#[inline(always)]
+pub fn fdim<T: Float>(x: T, y: T) -> T {
+  return x.fdim(y);
+}
+
+#[inline(always)]
+pub fn fma<T: Float>(a: T, b: T, c: T) -> T {
+  return a.fma(b, c);
+}
+
 #[inline(always)]
 pub fn copysign<T: Float>(sign: T, magnitude: T) -> T {
   return sign.copysign(magnitude);
@@ -570,6 +946,31 @@ pub fn rsqrt<T: Float>(x: T) -> T {
   return x.rsqrt();
 }
 
+#[inline(always)]
+pub fn rsqrt_accurate<T: Float>(x: T) -> T {
+  return x.rsqrt_accurate();
+}
+
+#[inline(always)]
+pub fn recip_fast<T: Float>(x: T) -> T {
+  return x.recip_fast();
+}
+
+#[inline(always)]
+pub fn recip_nr1<T: Float>(x: T) -> T {
+  return x.recip_nr1();
+}
+
+#[inline(always)]
+pub fn rsqrt_fast<T: Float>(x: T) -> T {
+  return x.rsqrt_fast();
+}
+
+#[inline(always)]
+pub fn rsqrt_nr1<T: Float>(x: T) -> T {
+  return x.rsqrt_nr1();
+}
+
 #[inline(always)]
 pub fn fract<T: Float>(x: T) -> T {
   return x.fract();
@@ -787,6 +1188,61 @@ pub trait Select<T: Vector> : Integer {
   fn bitselect(self, a: T, b: T) -> T;
 }
 
+/// Scalar bitmask view of the boolean vectors returned by comparisons, bit `i` is the sign bit of lane `i` which is the
+/// same bit `all`, `any` and `select` look at.
+pub trait Mask : Integer {
+  #[inline(always)]
+  fn to_bitmask(self) -> u16 {
+    return to_bitmask_lanes(self);
+  }
+
+  #[inline(always)]
+  fn from_bitmask(bits: u16) -> Self {
+    let mut x = Self::default();
+
+    for i in 0 .. Self::LANES {
+      x[i] = <Self::Scalar as scalar::Lane>::mask(bits & (1 << i) != 0);
+    }
+
+    return x;
+  }
+
+  #[inline(always)]
+  fn count_ones(self) -> u32 {
+    return self.to_bitmask().count_ones();
+  }
+
+  #[inline(always)]
+  fn first_set(self) -> Option<usize> {
+    let bits = self.to_bitmask();
+
+    return if bits == 0 { None } else { Some(bits.trailing_zeros() as usize) };
+  }
+
+  #[inline(always)]
+  fn iter_set_lanes(self) -> SetLanes {
+    return SetLanes::new(self.to_bitmask());
+  }
+}
+
+#[inline(always)]
+fn to_bitmask_lanes<T: Mask>(x: T) -> u16 {
+  let mut bits = 0;
+
+  for i in 0 .. T::LANES {
+    if x[i] & T::SIGN_MASK != T::Scalar::ZERO {
+      bits |= 1 << i;
+    }
+  }
+
+  return bits;
+}
+
+#[inline(always)]
+pub fn to_bitmask<T: Mask>(x: T) -> u16 {
+  return x.to_bitmask();
+}
+
 #[inline(always)]
 pub fn select<T: Vector, B: Select<T>>(condition: B, a: T, b: T) -> T {
   return condition.select(a, b);
@@ -796,3 +1252,120 @@ pub fn select<T: Vector, B: Select<T>>(condition: B, a: T, b: T) -> T {
 pub fn bitselect<T: Vector, B: Select<T>>(condition: B, a: T, b: T) -> T {
   return condition.bitselect(a, b);
 }
+
+/// Bitonic sorting networks over the lanes of 4, 8 and 16 lane vectors, every step swaps lane `i` with lane `i ^ j`
+/// and keeps the smaller or the larger of the two. The sort is not stable and lanes holding NaN end up in unspecified
+/// positions, the other lanes still come out sorted.
+pub trait Sort : Vector {
+  #[inline(always)]
+  fn sort_lanes(self) -> Self {
+    return bitonic_sort(self, false, &mut |_, _| {});
+  }
+
+  #[inline(always)]
+  fn sort_lanes_desc(self) -> Self {
+    return bitonic_sort(self, true, &mut |_, _| {});
+  }
+
+  /// Sorts the lanes in ascending order and applies the same permutation to `values`.
+  #[inline(always)]
+  fn sort_lanes_by_key<V: Vector>(self, values: V) -> (Self, V) {
+    assert_eq!(Self::LANES, V::LANES);
+
+    let mut values = values;
+    let keys = bitonic_sort(self, false, &mut |j, swap| {
+      values = V::Boolean::from_bitmask(swap).select(values, exchange_lanes(values, j));
+    });
+
+    return (keys, values);
+  }
+
+  /// Merges two vectors sorted in ascending order, the result holds the lowest lanes in the first vector and the
+  /// highest in the second one, both in ascending order.
+  #[inline(always)]
+  fn merge_sorted(self, other: Self) -> (Self, Self) {
+    let mut reversed = other;
+
+    for i in 0 .. Self::LANES {
+      reversed[i] = other[Self::LANES - 1 - i];
+    }
+
+    // Self followed by the reversed other is bitonic, after one step both halves are bitonic and split at the median
+    let swap = reversed.lt(self);
+    let lo = swap.select(self, reversed);
+    let hi = swap.select(reversed, self);
+
+    return (bitonic_merge(lo, Self::LANES, false, &mut |_, _| {}), bitonic_merge(hi, Self::LANES, false, &mut |_, _| {}));
+  }
+}
+
+#[inline(always)]
+fn exchange_lanes<T: Vector>(x: T, j: usize) -> T {
+  let mut y = x;
+
+  for i in 0 .. T::LANES {
+    y[i] = x[i ^ j];
+  }
+
+  return y;
+}
+
+// Sorts every block of `k` lanes of a vector made of bitonic blocks, blocks alternate between ascending and descending
+// unless `k` covers the whole vector. Each step reports the swapped lanes to `f` so that values can follow the keys.
+#[inline(always)]
+fn bitonic_merge<T: Vector>(x: T, k: usize, descending: bool, f: &mut FnMut(usize, u16)) -> T {
+  let mut x = x;
+  let mut j = k / 2;
+
+  while j > 0 {
+    let mut keep_min = 0;
+
+    for i in 0 .. T::LANES {
+      if ((i & j == 0) == (i & k == 0)) != descending {
+        keep_min |= 1 << i;
+      }
+    }
+
+    let y = exchange_lanes(x, j);
+    let swap = (y.lt(x).to_bitmask() & keep_min) | (y.gt(x).to_bitmask() & !keep_min);
+
+    f(j, swap);
+    x = T::Boolean::from_bitmask(swap).select(x, y);
+    j /= 2;
+  }
+
+  return x;
+}
+
+#[inline(always)]
+fn bitonic_sort<T: Vector>(x: T, descending: bool, f: &mut FnMut(usize, u16)) -> T {
+  let mut x = x;
+  let mut k = 2;
+
+  while k <= T::LANES {
+    x = bitonic_merge(x, k, descending, f);
+    k *= 2;
+  }
+
+  return x;
+}
+
+#[inline(always)]
+pub fn sort_lanes<T: Sort>(x: T) -> T {
+  return x.sort_lanes();
+}
+
+#[inline(always)]
+pub fn sort_lanes_desc<T: Sort>(x: T) -> T {
+  return x.sort_lanes_desc();
+}
+
+#[inline(always)]
+pub fn sort_lanes_by_key<T: Sort, V: Vector>(keys: T, values: V) -> (T, V) {
+  return keys.sort_lanes_by_key(values);
+}
+
+#[inline(always)]
+pub fn merge_sorted<T: Sort>(x: T, y: T) -> (T, T) {
+  return x.merge_sorted(y);
+}
@@ -0,0 +1,67 @@
+use std;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use ::*;
+use scalar::Lane;
+
+/// Wraps a float vector to give it `Eq`, `Hash` and a lexicographic `Ord`, e.g. to use it as a key in a map. Unlike
+/// the IEEE comparisons `-0.0` and `0.0` are the same value, and every NaN is equal to every other NaN and sorts after
+/// positive infinity.
+#[derive(Copy, Clone, Debug)]
+pub struct TotalOrder<T>(pub T);
+
+#[inline(always)]
+fn key<T: Float>(x: T, i: usize) -> i64 {
+  let x: f64 = x.extract(i as u32).cast();
+
+  if x.is_nan() {
+    return std::i64::MAX;
+  }
+
+  // Adding zero turns -0.0 into 0.0, flipping everything but the sign bit of negative numbers makes the bit patterns
+  // sort like the numbers
+  let bits = (x + 0.0).to_bits() as i64;
+
+  return bits ^ (((bits >> 63) as u64) >> 1) as i64;
+}
+
+impl<T: Float> PartialEq for TotalOrder<T> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    return (0 .. T::LANES).all(|i| key(self.0, i) == key(other.0, i));
+  }
+}
+
+impl<T: Float> Eq for TotalOrder<T> {
+}
+
+impl<T: Float> Hash for TotalOrder<T> {
+  #[inline]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    for i in 0 .. T::LANES {
+      key(self.0, i).hash(state);
+    }
+  }
+}
+
+impl<T: Float> PartialOrd for TotalOrder<T> {
+  #[inline(always)]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    return Some(self.cmp(other));
+  }
+}
+
+impl<T: Float> Ord for TotalOrder<T> {
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    for i in 0 .. T::LANES {
+      let ordering = key(self.0, i).cmp(&key(other.0, i));
+
+      if ordering != Ordering::Equal {
+        return ordering;
+      }
+    }
+
+    return Ordering::Equal;
+  }
+}
@@ -0,0 +1,121 @@
+use std;
+use ::*;
+use scalar::Lane;
+
+// Stand-ins for the platform intrinsics, vectors are plain aligned structs here so every operation is done lane by lane
+
+#[inline(always)]
+pub unsafe fn simd_add<T: Vector>(x: T, y: T) -> T {
+  return x.map_binary(y, &<T::Scalar as Lane>::lane_add);
+}
+
+#[inline(always)]
+pub unsafe fn simd_sub<T: Vector>(x: T, y: T) -> T {
+  return x.map_binary(y, &<T::Scalar as Lane>::lane_sub);
+}
+
+#[inline(always)]
+pub unsafe fn simd_mul<T: Vector>(x: T, y: T) -> T {
+  return x.map_binary(y, &<T::Scalar as Lane>::lane_mul);
+}
+
+#[inline(always)]
+pub unsafe fn simd_div<T: Vector>(x: T, y: T) -> T {
+  return x.map_binary(y, &<T::Scalar as Lane>::lane_div);
+}
+
+#[inline(always)]
+pub unsafe fn simd_shl<T: Vector>(x: T, y: T) -> T {
+  return x.map_binary(y, &<T::Scalar as Lane>::lane_shl);
+}
+
+#[inline(always)]
+pub unsafe fn simd_shr<T: Vector>(x: T, y: T) -> T {
+  return x.map_binary(y, &<T::Scalar as Lane>::lane_shr);
+}
+
+#[inline(always)]
+pub unsafe fn simd_and<T: Vector>(x: T, y: T) -> T {
+  return x.map_binary(y, &<T::Scalar as Lane>::lane_and);
+}
+
+#[inline(always)]
+pub unsafe fn simd_or<T: Vector>(x: T, y: T) -> T {
+  return x.map_binary(y, &<T::Scalar as Lane>::lane_or);
+}
+
+#[inline(always)]
+pub unsafe fn simd_xor<T: Vector>(x: T, y: T) -> T {
+  return x.map_binary(y, &<T::Scalar as Lane>::lane_xor);
+}
+
+#[inline(always)]
+unsafe fn compare<T: Vector, U: Vector>(x: T, y: T, f: &Fn(T::Scalar, T::Scalar) -> bool) -> U {
+  let mut r = U::from(0);
+
+  for i in 0 .. T::LANES as u32 {
+    r = simd_insert(r, i, <U::Scalar as Lane>::mask(f(simd_extract(x, i), simd_extract(y, i))));
+  }
+
+  return r;
+}
+
+#[inline(always)]
+pub unsafe fn simd_eq<T: Vector, U: Vector>(x: T, y: T) -> U {
+  return compare(x, y, &|a, b| a == b);
+}
+
+#[inline(always)]
+pub unsafe fn simd_ne<T: Vector, U: Vector>(x: T, y: T) -> U {
+  return compare(x, y, &|a, b| a != b);
+}
+
+#[inline(always)]
+pub unsafe fn simd_lt<T: Vector, U: Vector>(x: T, y: T) -> U {
+  return compare(x, y, &|a, b| a < b);
+}
+
+#[inline(always)]
+pub unsafe fn simd_le<T: Vector, U: Vector>(x: T, y: T) -> U {
+  return compare(x, y, &|a, b| a <= b);
+}
+
+#[inline(always)]
+pub unsafe fn simd_gt<T: Vector, U: Vector>(x: T, y: T) -> U {
+  return compare(x, y, &|a, b| a > b);
+}
+
+#[inline(always)]
+pub unsafe fn simd_ge<T: Vector, U: Vector>(x: T, y: T) -> U {
+  return compare(x, y, &|a, b| a >= b);
+}
+
+#[inline(always)]
+pub unsafe fn simd_cast<T: Vector, U: Vector>(x: T) -> U {
+  assert_eq!(T::LANES, U::LANES);
+
+  let mut r = U::from(0);
+
+  for i in 0 .. T::LANES as u32 {
+    r = simd_insert(r, i, simd_extract(x, i).cast());
+  }
+
+  return r;
+}
+
+#[inline(always)]
+pub unsafe fn simd_insert<T: Vector>(x: T, i: u32, e: T::Scalar) -> T {
+  assert!((i as usize) < T::LANES);
+
+  let mut r = x;
+  std::ptr::write((&mut r as *mut T as *mut T::Scalar).offset(i as isize), e);
+
+  return r;
+}
+
+#[inline(always)]
+pub unsafe fn simd_extract<T: Vector>(x: T, i: u32) -> T::Scalar {
+  assert!((i as usize) < T::LANES);
+
+  return std::ptr::read((&x as *const T as *const T::Scalar).offset(i as isize));
+}
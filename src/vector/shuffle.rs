@@ -0,0 +1,10 @@
+/// Lane permutations, written out with the lane constructors for every width so they compile to a single shuffle for
+/// the power of two distances.
+pub trait Shuffle : Copy {
+  /// Exchanges every block of `n` lanes with its neighbour, lane `i` of the result is lane `i ^ n`. Lanes without a
+  /// partner, like lane 1 of a three lane vector for `n = 2`, stay where they are.
+  fn swap_lanes(self, n: usize) -> Self;
+
+  /// Moves every lane up by `n` lanes, the lowest `n` lanes are taken from `fill`.
+  fn shift_lanes_up(self, n: usize, fill: Self) -> Self;
+}
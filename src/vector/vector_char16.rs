@@ -1,10 +1,24 @@
 use std;
 use ::*;
 
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+extern "platform-intrinsic" {
+  fn x86_mm_movemask_epi8(x: char16) -> i32;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+sse2"]
+#[inline]
+unsafe fn to_bitmask_sse2(x: char16) -> u16 {
+  return x86_mm_movemask_epi8(x) as u16;
+}
+
 impl Vector for char16 {
   type Scalar = i8;
   type Boolean = char16;
 
+  const LANES: usize = 16;
+
   type CharVector = char16;
   type ShortVector = short16;
   type IntVector = int16;
@@ -113,6 +127,21 @@ impl Select<uchar16> for char16 {
   }
 }
 
+impl Mask for char16 {
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn to_bitmask(self) -> u16 {
+    if dispatch::has(dispatch::SSE2) {
+      return unsafe { to_bitmask_sse2(self) };
+    }
+
+    return super::to_bitmask_lanes(self);
+  }
+}
+
+impl Sort for char16 {
+}
+
 impl char16 {
   #[inline(always)]
   pub fn lo(self) -> char8 {
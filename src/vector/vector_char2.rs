@@ -5,6 +5,8 @@ impl Vector for char2 {
   type Scalar = i8;
   type Boolean = char2;
 
+  const LANES: usize = 2;
+
   type CharVector = char2;
   type ShortVector = short2;
   type IntVector = int2;
@@ -113,6 +115,9 @@ impl Select<uchar2> for char2 {
   }
 }
 
+impl Mask for char2 {
+}
+
 impl char2 {
   #[inline(always)]
   pub fn lo(self) -> i8 {
@@ -5,6 +5,8 @@ impl Vector for char3 {
   type Scalar = i8;
   type Boolean = char3;
 
+  const LANES: usize = 3;
+
   type CharVector = char3;
   type ShortVector = short3;
   type IntVector = int3;
@@ -153,6 +155,9 @@ impl Select<uchar3> for char3 {
   }
 }
 
+impl Mask for char3 {
+}
+
 impl char3 {
   #[inline(always)]
   pub fn lo(self) -> char2 {
@@ -5,6 +5,8 @@ impl Vector for char4 {
   type Scalar = i8;
   type Boolean = char4;
 
+  const LANES: usize = 4;
+
   type CharVector = char4;
   type ShortVector = short4;
   type IntVector = int4;
@@ -113,6 +115,12 @@ impl Select<uchar4> for char4 {
   }
 }
 
+impl Mask for char4 {
+}
+
+impl Sort for char4 {
+}
+
 impl char4 {
   #[inline(always)]
   pub fn lo(self) -> char2 {
@@ -5,6 +5,8 @@ impl Vector for char8 {
   type Scalar = i8;
   type Boolean = char8;
 
+  const LANES: usize = 8;
+
   type CharVector = char8;
   type ShortVector = short8;
   type IntVector = int8;
@@ -113,6 +115,12 @@ impl Select<uchar8> for char8 {
   }
 }
 
+impl Mask for char8 {
+}
+
+impl Sort for char8 {
+}
+
 impl char8 {
   #[inline(always)]
   pub fn lo(self) -> char4 {
@@ -5,6 +5,8 @@ impl Vector for double16 {
   type Scalar = f64;
   type Boolean = long16;
 
+  const LANES: usize = 16;
+
   type CharVector = char16;
   type ShortVector = short16;
   type IntVector = int16;
@@ -93,11 +95,15 @@ impl Float for double16 {
   type FloatScalar = f64;
 
   const SIGN_MASK: i64 = std::i64::MAX;
+  const EXPONENT_MASK: i64 = 0x7FF0000000000000;
 }
 
 impl Geometry for double16 {
 }
 
+impl Sort for double16 {
+}
+
 impl double16 {
   #[inline(always)]
   pub fn lo(self) -> double8 {
@@ -5,6 +5,8 @@ impl Vector for double2 {
   type Scalar = f64;
   type Boolean = long2;
 
+  const LANES: usize = 2;
+
   type CharVector = char2;
   type ShortVector = short2;
   type IntVector = int2;
@@ -102,6 +104,7 @@ impl Float for double2 {
   type FloatScalar = f64;
 
   const SIGN_MASK: i64 = std::i64::MAX;
+  const EXPONENT_MASK: i64 = 0x7FF0000000000000;
 }
 
 impl Geometry for double2 {
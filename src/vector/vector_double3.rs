@@ -5,6 +5,8 @@ impl Vector for double3 {
   type Scalar = f64;
   type Boolean = long3;
 
+  const LANES: usize = 3;
+
   type CharVector = char3;
   type ShortVector = short3;
   type IntVector = int3;
@@ -86,7 +88,7 @@ impl Cross for double3 {
 
   #[inline(always)]
   fn cross(self, other: Self) -> Self::CrossProduct {
-    let a = self * double3(other.2, other.0, other.1) - double3(self.2, self.0, self.1) * other;
+    let a = double3(self.2, self.0, self.1) * other - self * double3(other.2, other.0, other.1);
 
     return double3(a.2, a.0, a.1);
   }
@@ -104,6 +106,7 @@ impl Float for double3 {
   type FloatScalar = f64;
 
   const SIGN_MASK: i64 = std::i64::MAX;
+  const EXPONENT_MASK: i64 = 0x7FF0000000000000;
 }
 
 impl Geometry for double3 {
@@ -1,10 +1,86 @@
 use std;
 use ::*;
 
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+extern "platform-intrinsic" {
+  fn x86_mm256_sqrt_pd(x: double4) -> double4;
+  fn x86_mm256_max_pd(x: double4, y: double4) -> double4;
+  fn x86_mm256_min_pd(x: double4, y: double4) -> double4;
+  fn x86_mm256_fmadd_pd(x: double4, y: double4, z: double4) -> double4;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn max_avx(x: double4, y: double4) -> double4 {
+  // The x86 instructions return the second operand if either one is NaN, we prefer the number like `f64::max`
+  return y.ne(y).bitselect(x86_mm256_max_pd(x, y), x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn min_avx(x: double4, y: double4) -> double4 {
+  return y.ne(y).bitselect(x86_mm256_min_pd(x, y), x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn reduce_add_avx(x: double4) -> f64 {
+  let x = x + double4(x.2, x.3, x.0, x.1);
+  let x = x + double4(x.1, x.0, x.3, x.2);
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn reduce_max_avx(x: double4) -> f64 {
+  let x = max_avx(x, double4(x.2, x.3, x.0, x.1));
+  let x = max_avx(x, double4(x.1, x.0, x.3, x.2));
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn reduce_min_avx(x: double4) -> f64 {
+  let x = min_avx(x, double4(x.2, x.3, x.0, x.1));
+  let x = min_avx(x, double4(x.1, x.0, x.3, x.2));
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn dot_avx(x: double4, y: double4) -> f64 {
+  return reduce_add_avx(x * y);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx,+fma"]
+#[inline]
+unsafe fn add_mul_fma(x: double4, a: double4, b: double4) -> double4 {
+  return x86_mm256_fmadd_pd(a, b, x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn sqrt_avx(x: double4) -> double4 {
+  return x86_mm256_sqrt_pd(x);
+}
+
 impl Vector for double4 {
   type Scalar = f64;
   type Boolean = long4;
 
+  const LANES: usize = 4;
+
   type CharVector = char4;
   type ShortVector = short4;
   type IntVector = int4;
@@ -40,6 +116,84 @@ impl Vector for double4 {
     return x.bitselect(Self::from(0), self);
   }
 
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn add_mul(self, a: Self, b: Self) -> Self {
+    if dispatch::has(dispatch::AVX | dispatch::FMA) {
+      return unsafe { add_mul_fma(self, a, b) };
+    }
+
+    return a * b + self;
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn max(self, other: Self) -> Self {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { max_avx(self, other) };
+    }
+
+    return self.map_binary(other, &<f64 as scalar::Scalar>::max);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn min(self, other: Self) -> Self {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { min_avx(self, other) };
+    }
+
+    return self.map_binary(other, &<f64 as scalar::Scalar>::min);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_add(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { reduce_add_avx(self) };
+    }
+
+    return self.reduce_tree(&<f64 as std::ops::Add>::add);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline(always)]
+  fn reduce_add(self) -> Self::Scalar {
+    return self.reduce_tree(&<f64 as std::ops::Add>::add);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_max(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { reduce_max_avx(self) };
+    }
+
+    return self.reduce_tree(&<f64 as scalar::Scalar>::max);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline(always)]
+  fn reduce_max(self) -> Self::Scalar {
+    return self.reduce_tree(&<f64 as scalar::Scalar>::max);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_min(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { reduce_min_avx(self) };
+    }
+
+    return self.reduce_tree(&<f64 as scalar::Scalar>::min);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline(always)]
+  fn reduce_min(self) -> Self::Scalar {
+    return self.reduce_tree(&<f64 as scalar::Scalar>::min);
+  }
+
   #[inline(always)]
   fn to_char_sat(self) -> char4 {
     return double4::to_char(self.clamp(Self::broadcast(std::i8::MIN as f64), Self::broadcast(std::i8::MAX as f64)));
@@ -83,6 +237,18 @@ impl Vector for double4 {
 
 impl Dot<double4> for double4 {
   type DotProduct = f64;
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn dot(self, other: Self) -> Self::DotProduct {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { dot_avx(self, other) };
+    }
+
+    return reduce_add(self * other);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
   #[inline(always)]
   fn dot(self, other: Self) -> Self::DotProduct {
     return reduce_add(self * other);
@@ -93,11 +259,35 @@ impl Float for double4 {
   type FloatScalar = f64;
 
   const SIGN_MASK: i64 = std::i64::MAX;
+  const EXPONENT_MASK: i64 = 0x7FF0000000000000;
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn fma(self, b: Self, c: Self) -> Self {
+    if dispatch::has(dispatch::AVX | dispatch::FMA) {
+      return unsafe { add_mul_fma(c, self, b) };
+    }
+
+    return super::fma_lanes(self, b, c);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn sqrt(self) -> Self {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { sqrt_avx(self) };
+    }
+
+    return self.map_unary(&<f64 as scalar::FloatScalar>::sqrt);
+  }
 }
 
 impl Geometry for double4 {
 }
 
+impl Sort for double4 {
+}
+
 impl double4 {
   #[inline(always)]
   pub fn lo(self) -> double2 {
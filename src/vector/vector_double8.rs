@@ -1,10 +1,111 @@
 use std;
 use ::*;
 
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+extern {
+  #[link_name = "llvm.sqrt.v8f64"]
+  fn sqrt_v8f64(x: double8) -> double8;
+
+  #[link_name = "llvm.fma.v8f64"]
+  fn fma_v8f64(a: double8, b: double8, c: double8) -> double8;
+
+  #[link_name = "llvm.x86.avx512.rsqrt14.pd.512"]
+  fn rsqrt14_pd_512(x: double8, src: double8, mask: u8) -> double8;
+
+  #[link_name = "llvm.x86.avx512.mask.max.pd.512"]
+  fn max_pd_512(x: double8, y: double8, src: double8, mask: u8, rounding: i32) -> double8;
+
+  #[link_name = "llvm.x86.avx512.mask.min.pd.512"]
+  fn min_pd_512(x: double8, y: double8, src: double8, mask: u8, rounding: i32) -> double8;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn max_avx512(x: double8, y: double8) -> double8 {
+  // The x86 instructions return the second operand if either one is NaN, we prefer the number like `f64::max`
+  return y.ne(y).bitselect(max_pd_512(x, y, double8::from(0), 0xFF, 4), x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn min_avx512(x: double8, y: double8) -> double8 {
+  return y.ne(y).bitselect(min_pd_512(x, y, double8::from(0), 0xFF, 4), x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn reduce_add_avx512(x: double8) -> f64 {
+  let x = x + double8(x.4, x.5, x.6, x.7, x.0, x.1, x.2, x.3);
+  let x = x + double8(x.2, x.3, x.0, x.1, x.6, x.7, x.4, x.5);
+  let x = x + double8(x.1, x.0, x.3, x.2, x.5, x.4, x.7, x.6);
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn reduce_max_avx512(x: double8) -> f64 {
+  let x = max_avx512(x, double8(x.4, x.5, x.6, x.7, x.0, x.1, x.2, x.3));
+  let x = max_avx512(x, double8(x.2, x.3, x.0, x.1, x.6, x.7, x.4, x.5));
+  let x = max_avx512(x, double8(x.1, x.0, x.3, x.2, x.5, x.4, x.7, x.6));
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn reduce_min_avx512(x: double8) -> f64 {
+  let x = min_avx512(x, double8(x.4, x.5, x.6, x.7, x.0, x.1, x.2, x.3));
+  let x = min_avx512(x, double8(x.2, x.3, x.0, x.1, x.6, x.7, x.4, x.5));
+  let x = min_avx512(x, double8(x.1, x.0, x.3, x.2, x.5, x.4, x.7, x.6));
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn dot_avx512(x: double8, y: double8) -> f64 {
+  return reduce_add_avx512(x * y);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn add_mul_avx512(x: double8, a: double8, b: double8) -> double8 {
+  return fma_v8f64(a, b, x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn sqrt_avx512(x: double8) -> double8 {
+  return sqrt_v8f64(x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn rsqrt_nr2_avx512(x: double8) -> double8 {
+  // The estimate is good to 14 bits, it takes two Newton-Raphson steps to get close to the 53 bits of a double
+  let y = 0.5 * x.eq(double8::broadcast(0.0)).bitselect(x, double8::broadcast(-std::f64::INFINITY));
+  let r = rsqrt14_pd_512(x, double8::from(0), 0xFF);
+  let r = r * (1.5 - y * r * r);
+
+  return r * (1.5 - y * r * r);
+}
+
 impl Vector for double8 {
   type Scalar = f64;
   type Boolean = long8;
 
+  const LANES: usize = 8;
+
   type CharVector = char8;
   type ShortVector = short8;
   type IntVector = int8;
@@ -40,6 +141,84 @@ impl Vector for double8 {
     return x.bitselect(Self::from(0), self);
   }
 
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn add_mul(self, a: Self, b: Self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { add_mul_avx512(self, a, b) };
+    }
+
+    return a * b + self;
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn max(self, other: Self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { max_avx512(self, other) };
+    }
+
+    return self.map_binary(other, &<f64 as scalar::Scalar>::max);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn min(self, other: Self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { min_avx512(self, other) };
+    }
+
+    return self.map_binary(other, &<f64 as scalar::Scalar>::min);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_add(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { reduce_add_avx512(self) };
+    }
+
+    return self.reduce_tree(&<f64 as std::ops::Add>::add);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline(always)]
+  fn reduce_add(self) -> Self::Scalar {
+    return self.reduce_tree(&<f64 as std::ops::Add>::add);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_max(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { reduce_max_avx512(self) };
+    }
+
+    return self.reduce_tree(&<f64 as scalar::Scalar>::max);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline(always)]
+  fn reduce_max(self) -> Self::Scalar {
+    return self.reduce_tree(&<f64 as scalar::Scalar>::max);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_min(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { reduce_min_avx512(self) };
+    }
+
+    return self.reduce_tree(&<f64 as scalar::Scalar>::min);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline(always)]
+  fn reduce_min(self) -> Self::Scalar {
+    return self.reduce_tree(&<f64 as scalar::Scalar>::min);
+  }
+
   #[inline(always)]
   fn to_char_sat(self) -> char8 {
     return double8::to_char(self.clamp(Self::broadcast(std::i8::MIN as f64), Self::broadcast(std::i8::MAX as f64)));
@@ -83,6 +262,18 @@ impl Vector for double8 {
 
 impl Dot<double8> for double8 {
   type DotProduct = f64;
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn dot(self, other: Self) -> Self::DotProduct {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { dot_avx512(self, other) };
+    }
+
+    return reduce_add(self * other);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
   #[inline(always)]
   fn dot(self, other: Self) -> Self::DotProduct {
     return reduce_add(self * other);
@@ -93,11 +284,45 @@ impl Float for double8 {
   type FloatScalar = f64;
 
   const SIGN_MASK: i64 = std::i64::MAX;
+  const EXPONENT_MASK: i64 = 0x7FF0000000000000;
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn fma(self, b: Self, c: Self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { add_mul_avx512(c, self, b) };
+    }
+
+    return super::fma_lanes(self, b, c);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn sqrt(self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { sqrt_avx512(self) };
+    }
+
+    return self.map_unary(&<f64 as scalar::FloatScalar>::sqrt);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt(self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { rsqrt_nr2_avx512(self) };
+    }
+
+    return self.sqrt().recip();
+  }
 }
 
 impl Geometry for double8 {
 }
 
+impl Sort for double8 {
+}
+
 impl double8 {
   #[inline(always)]
   pub fn lo(self) -> double4 {
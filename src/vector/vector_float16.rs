@@ -1,10 +1,137 @@
 use std;
 use ::*;
 
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+extern {
+  #[link_name = "llvm.sqrt.v16f32"]
+  fn sqrt_v16f32(x: float16) -> float16;
+
+  #[link_name = "llvm.fma.v16f32"]
+  fn fma_v16f32(a: float16, b: float16, c: float16) -> float16;
+
+  #[link_name = "llvm.x86.avx512.rsqrt14.ps.512"]
+  fn rsqrt14_ps_512(x: float16, src: float16, mask: u16) -> float16;
+
+  #[link_name = "llvm.x86.avx512.rcp14.ps.512"]
+  fn rcp14_ps_512(x: float16, src: float16, mask: u16) -> float16;
+
+  #[link_name = "llvm.x86.avx512.mask.max.ps.512"]
+  fn max_ps_512(x: float16, y: float16, src: float16, mask: u16, rounding: i32) -> float16;
+
+  #[link_name = "llvm.x86.avx512.mask.min.ps.512"]
+  fn min_ps_512(x: float16, y: float16, src: float16, mask: u16, rounding: i32) -> float16;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn max_avx512(x: float16, y: float16) -> float16 {
+  // The x86 instructions return the second operand if either one is NaN, we prefer the number like `f32::max`
+  return y.ne(y).bitselect(max_ps_512(x, y, float16::from(0), 0xFFFF, 4), x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn min_avx512(x: float16, y: float16) -> float16 {
+  return y.ne(y).bitselect(min_ps_512(x, y, float16::from(0), 0xFFFF, 4), x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn reduce_add_avx512(x: float16) -> f32 {
+  let x = x + float16(x.8, x.9, x.10, x.11, x.12, x.13, x.14, x.15, x.0, x.1, x.2, x.3, x.4, x.5, x.6, x.7);
+  let x = x + float16(x.4, x.5, x.6, x.7, x.0, x.1, x.2, x.3, x.12, x.13, x.14, x.15, x.8, x.9, x.10, x.11);
+  let x = x + float16(x.2, x.3, x.0, x.1, x.6, x.7, x.4, x.5, x.10, x.11, x.8, x.9, x.14, x.15, x.12, x.13);
+  let x = x + float16(x.1, x.0, x.3, x.2, x.5, x.4, x.7, x.6, x.9, x.8, x.11, x.10, x.13, x.12, x.15, x.14);
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn reduce_max_avx512(x: float16) -> f32 {
+  let x = max_avx512(x, float16(x.8, x.9, x.10, x.11, x.12, x.13, x.14, x.15, x.0, x.1, x.2, x.3, x.4, x.5, x.6, x.7));
+  let x = max_avx512(x, float16(x.4, x.5, x.6, x.7, x.0, x.1, x.2, x.3, x.12, x.13, x.14, x.15, x.8, x.9, x.10, x.11));
+  let x = max_avx512(x, float16(x.2, x.3, x.0, x.1, x.6, x.7, x.4, x.5, x.10, x.11, x.8, x.9, x.14, x.15, x.12, x.13));
+  let x = max_avx512(x, float16(x.1, x.0, x.3, x.2, x.5, x.4, x.7, x.6, x.9, x.8, x.11, x.10, x.13, x.12, x.15, x.14));
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn reduce_min_avx512(x: float16) -> f32 {
+  let x = min_avx512(x, float16(x.8, x.9, x.10, x.11, x.12, x.13, x.14, x.15, x.0, x.1, x.2, x.3, x.4, x.5, x.6, x.7));
+  let x = min_avx512(x, float16(x.4, x.5, x.6, x.7, x.0, x.1, x.2, x.3, x.12, x.13, x.14, x.15, x.8, x.9, x.10, x.11));
+  let x = min_avx512(x, float16(x.2, x.3, x.0, x.1, x.6, x.7, x.4, x.5, x.10, x.11, x.8, x.9, x.14, x.15, x.12, x.13));
+  let x = min_avx512(x, float16(x.1, x.0, x.3, x.2, x.5, x.4, x.7, x.6, x.9, x.8, x.11, x.10, x.13, x.12, x.15, x.14));
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn dot_avx512(x: float16, y: float16) -> f32 {
+  return reduce_add_avx512(x * y);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn add_mul_avx512(x: float16, a: float16, b: float16) -> float16 {
+  return fma_v16f32(a, b, x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn sqrt_avx512(x: float16) -> float16 {
+  return sqrt_v16f32(x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn recip_fast_avx512(x: float16) -> float16 {
+  return rcp14_ps_512(x, float16::from(0), 0xFFFF);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn recip_nr1_avx512(x: float16) -> float16 {
+  let r = rcp14_ps_512(x, float16::from(0), 0xFFFF);
+
+  return r * (2.0 - x.eq(float16::broadcast(0.0)).bitselect(x, float16::broadcast(-std::f32::INFINITY)) * r);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn rsqrt_fast_avx512(x: float16) -> float16 {
+  return rsqrt14_ps_512(x, float16::from(0), 0xFFFF);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx512f"]
+#[inline]
+unsafe fn rsqrt_nr1_avx512(x: float16) -> float16 {
+  let r = rsqrt14_ps_512(x, float16::from(0), 0xFFFF);
+
+  return r * (1.5 - 0.5 * x.eq(float16::broadcast(0.0)).bitselect(x, float16::broadcast(-std::f32::INFINITY)) * r * r);
+}
+
 impl Vector for float16 {
   type Scalar = f32;
   type Boolean = int16;
 
+  const LANES: usize = 16;
+
   type CharVector = char16;
   type ShortVector = short16;
   type IntVector = int16;
@@ -40,6 +167,84 @@ impl Vector for float16 {
     return x.bitselect(Self::from(0), self);
   }
 
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn add_mul(self, a: Self, b: Self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { add_mul_avx512(self, a, b) };
+    }
+
+    return a * b + self;
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn max(self, other: Self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { max_avx512(self, other) };
+    }
+
+    return self.map_binary(other, &<f32 as scalar::Scalar>::max);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn min(self, other: Self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { min_avx512(self, other) };
+    }
+
+    return self.map_binary(other, &<f32 as scalar::Scalar>::min);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_add(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { reduce_add_avx512(self) };
+    }
+
+    return self.reduce_tree(&<f32 as std::ops::Add>::add);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline(always)]
+  fn reduce_add(self) -> Self::Scalar {
+    return self.reduce_tree(&<f32 as std::ops::Add>::add);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_max(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { reduce_max_avx512(self) };
+    }
+
+    return self.reduce_tree(&<f32 as scalar::Scalar>::max);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline(always)]
+  fn reduce_max(self) -> Self::Scalar {
+    return self.reduce_tree(&<f32 as scalar::Scalar>::max);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_min(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { reduce_min_avx512(self) };
+    }
+
+    return self.reduce_tree(&<f32 as scalar::Scalar>::min);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline(always)]
+  fn reduce_min(self) -> Self::Scalar {
+    return self.reduce_tree(&<f32 as scalar::Scalar>::min);
+  }
+
   #[inline(always)]
   fn to_char_sat(self) -> char16 {
     return float16::to_char(self.clamp(Self::broadcast(std::i8::MIN as f32), Self::broadcast(std::i8::MAX as f32)));
@@ -83,6 +288,18 @@ impl Vector for float16 {
 
 impl Dot<float16> for float16 {
   type DotProduct = f32;
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn dot(self, other: Self) -> Self::DotProduct {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { dot_avx512(self, other) };
+    }
+
+    return reduce_add(self * other);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
   #[inline(always)]
   fn dot(self, other: Self) -> Self::DotProduct {
     return reduce_add(self * other);
@@ -93,11 +310,85 @@ impl Float for float16 {
   type FloatScalar = f32;
 
   const SIGN_MASK: i32 = std::i32::MAX;
+  const EXPONENT_MASK: i32 = 0x7F800000;
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn fma(self, b: Self, c: Self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { add_mul_avx512(c, self, b) };
+    }
+
+    return super::fma_lanes(self, b, c);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn sqrt(self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { sqrt_avx512(self) };
+    }
+
+    return self.map_unary(&<f32 as scalar::FloatScalar>::sqrt);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn recip_fast(self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { recip_fast_avx512(self) };
+    }
+
+    return self.recip();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn recip_nr1(self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { recip_nr1_avx512(self) };
+    }
+
+    return self.recip();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt(self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { rsqrt_nr1_avx512(self) };
+    }
+
+    return self.sqrt().recip();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt_fast(self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { rsqrt_fast_avx512(self) };
+    }
+
+    return self.rsqrt();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt_nr1(self) -> Self {
+    if dispatch::has(dispatch::AVX512F) {
+      return unsafe { rsqrt_nr1_avx512(self) };
+    }
+
+    return self.rsqrt();
+  }
 }
 
 impl Geometry for float16 {
 }
 
+impl Sort for float16 {
+}
+
 impl float16 {
   #[inline(always)]
   pub fn lo(self) -> float8 {
@@ -5,6 +5,8 @@ impl Vector for float2 {
   type Scalar = f32;
   type Boolean = int2;
 
+  const LANES: usize = 2;
+
   type CharVector = char2;
   type ShortVector = short2;
   type IntVector = int2;
@@ -102,6 +104,31 @@ impl Float for float2 {
   type FloatScalar = f32;
 
   const SIGN_MASK: i32 = std::i32::MAX;
+  const EXPONENT_MASK: i32 = 0x7F800000;
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn recip_fast(self) -> Self {
+    return float4(self.0, self.1, 1.0, 1.0).recip_fast().lo();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn recip_nr1(self) -> Self {
+    return float4(self.0, self.1, 1.0, 1.0).recip_nr1().lo();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt_fast(self) -> Self {
+    return float4(self.0, self.1, 1.0, 1.0).rsqrt_fast().lo();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt_nr1(self) -> Self {
+    return float4(self.0, self.1, 1.0, 1.0).rsqrt_nr1().lo();
+  }
 }
 
 impl Geometry for float2 {
@@ -5,6 +5,8 @@ impl Vector for float3 {
   type Scalar = f32;
   type Boolean = int3;
 
+  const LANES: usize = 3;
+
   type CharVector = char3;
   type ShortVector = short3;
   type IntVector = int3;
@@ -104,6 +106,39 @@ impl Float for float3 {
   type FloatScalar = f32;
 
   const SIGN_MASK: i32 = std::i32::MAX;
+  const EXPONENT_MASK: i32 = 0x7F800000;
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn recip_fast(self) -> Self {
+    let x = float4(self.0, self.1, self.2, 1.0).recip_fast();
+
+    return float3(x.0, x.1, x.2);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn recip_nr1(self) -> Self {
+    let x = float4(self.0, self.1, self.2, 1.0).recip_nr1();
+
+    return float3(x.0, x.1, x.2);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt_fast(self) -> Self {
+    let x = float4(self.0, self.1, self.2, 1.0).rsqrt_fast();
+
+    return float3(x.0, x.1, x.2);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt_nr1(self) -> Self {
+    let x = float4(self.0, self.1, self.2, 1.0).rsqrt_nr1();
+
+    return float3(x.0, x.1, x.2);
+  }
 }
 
 impl Geometry for float3 {
@@ -1,27 +1,111 @@
 use std;
 use ::*;
 
-#[cfg(target_feature = "sse")]
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
 extern "platform-intrinsic" {
   fn x86_mm_sqrt_ps(x: float4) -> float4;
   fn x86_mm_rsqrt_ps(x: float4) -> float4;
+  fn x86_mm_rcp_ps(x: float4) -> float4;
 }
 
-#[cfg(target_feature = "sse4.1")]
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
 extern "platform-intrinsic" {
   fn x86_mm_dp_ps(x: float4, y: float4, z: i32) -> float4;
 }
 
-#[cfg(target_feature = "avx2")] // TODO: Compiler bug, should actually be "fma"
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
 extern {
   #[link_name = "llvm.fma.v4f32"]
   fn fma_v4f32(a: float4, b: float4, c: float4) -> float4;
 }
 
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+sse4.1"]
+#[inline]
+unsafe fn dot_sse41(x: float4, y: float4) -> f32 {
+  return x86_mm_dp_ps(x, y, 0xF1).0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+sse"]
+#[inline]
+unsafe fn sqrt_sse(x: float4) -> float4 {
+  return x86_mm_sqrt_ps(x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+sse"]
+#[inline]
+unsafe fn recip_fast_sse(x: float4) -> float4 {
+  return x86_mm_rcp_ps(x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+sse"]
+#[inline]
+unsafe fn recip_nr1_sse(x: float4) -> float4 {
+  let r = x86_mm_rcp_ps(x);
+
+  // Zero lanes are replaced with -∞ so that the step keeps the ∞ estimate instead of computing 0 * ∞
+  return r * (2.0 - x.eq(float4::broadcast(0.0)).bitselect(x, float4::broadcast(-std::f32::INFINITY)) * r);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+sse"]
+#[inline]
+unsafe fn rsqrt_fast_sse(x: float4) -> float4 {
+  return x86_mm_rsqrt_ps(x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+sse"]
+#[inline]
+unsafe fn rsqrt_nr1_sse(x: float4) -> float4 {
+  let r = x86_mm_rsqrt_ps(x);
+
+  return r * (1.5 - 0.5 * x.eq(float4::broadcast(0.0)).bitselect(x, float4::broadcast(-std::f32::INFINITY)) * r * r);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+fma"]
+#[inline]
+unsafe fn add_mul_fma(x: float4, a: float4, b: float4) -> float4 {
+  return fma_v4f32(a, b, x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+sse4.1"]
+#[inline]
+unsafe fn dot_slice_sse41(x: &[float4], y: &[float4], out: &mut [f32]) {
+  for ((a, b), d) in x.iter().zip(y).zip(out.iter_mut()) {
+    *d = dot_sse41(*a, *b);
+  }
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+fma"]
+#[inline]
+unsafe fn add_mul_slice_fma(x: &mut [float4], a: &[float4], b: &[float4]) {
+  for ((r, a), b) in x.iter_mut().zip(a).zip(b) {
+    *r = add_mul_fma(*r, *a, *b);
+  }
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+sse"]
+#[inline]
+unsafe fn rsqrt_slice_sse(x: &mut [float4]) {
+  for r in x.iter_mut() {
+    *r = rsqrt_nr1_sse(*r);
+  }
+}
+
 impl Vector for float4 {
   type Scalar = f32;
   type Boolean = int4;
 
+  const LANES: usize = 4;
+
   type CharVector = char4;
   type ShortVector = short4;
   type IntVector = int4;
@@ -57,10 +141,14 @@ impl Vector for float4 {
     return x.bitselect(Self::from(0), self);
   }
 
-  #[cfg(target_feature = "avx2")] // TODO: Compiler bug, should actually be "fma"
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
   #[inline(always)]
   fn add_mul(self, a: Self, b: Self) -> Self {
-    return unsafe { fma_v4f32(a, b, self) };
+    if dispatch::has(dispatch::FMA) {
+      return unsafe { add_mul_fma(self, a, b) };
+    }
+
+    return a * b + self;
   }
 
   #[inline(always)]
@@ -107,13 +195,17 @@ impl Vector for float4 {
 impl Dot<float4> for float4 {
   type DotProduct = f32;
 
-  #[cfg(target_feature = "sse4.1")]
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
   #[inline(always)]
   fn dot(self, other: Self) -> Self::DotProduct {
-    return unsafe { x86_mm_dp_ps(self, other, 0xF1).0 };
+    if dispatch::has(dispatch::SSE41) {
+      return unsafe { dot_sse41(self, other) };
+    }
+
+    return reduce_add(self * other);
   }
 
-  #[cfg(not(target_feature = "sse4.1"))]
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
   #[inline(always)]
   fn dot(self, other: Self) -> Self::DotProduct {
     return reduce_add(self * other);
@@ -124,25 +216,85 @@ impl Float for float4 {
   type FloatScalar = f32;
 
   const SIGN_MASK: i32 = std::i32::MAX;
+  const EXPONENT_MASK: i32 = 0x7F800000;
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn fma(self, b: Self, c: Self) -> Self {
+    if dispatch::has(dispatch::FMA) {
+      return unsafe { add_mul_fma(c, self, b) };
+    }
 
-  #[cfg(target_feature = "sse")]
+    return super::fma_lanes(self, b, c);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
   #[inline(always)]
   fn sqrt(self) -> Self {
-    return unsafe { x86_mm_sqrt_ps(self) };
+    if dispatch::has(dispatch::SSE) {
+      return unsafe { sqrt_sse(self) };
+    }
+
+    return self.map_unary(&<f32 as scalar::FloatScalar>::sqrt);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn recip_fast(self) -> Self {
+    if dispatch::has(dispatch::SSE) {
+      return unsafe { recip_fast_sse(self) };
+    }
+
+    return self.recip();
   }
 
-  #[cfg(target_feature = "sse")]
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn recip_nr1(self) -> Self {
+    if dispatch::has(dispatch::SSE) {
+      return unsafe { recip_nr1_sse(self) };
+    }
+
+    return self.recip();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
   #[inline(always)]
   fn rsqrt(self) -> Self {
-    let r = unsafe { x86_mm_rsqrt_ps(self) };
+    if dispatch::has(dispatch::SSE) {
+      return unsafe { rsqrt_nr1_sse(self) };
+    }
+
+    return self.sqrt().recip();
+  }
 
-    return r * (1.5 - 0.5 * self.eq(Self::broadcast(0.0)).bitselect(self, Self::broadcast(-std::f32::INFINITY)) * r * r);
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt_fast(self) -> Self {
+    if dispatch::has(dispatch::SSE) {
+      return unsafe { rsqrt_fast_sse(self) };
+    }
+
+    return self.rsqrt();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt_nr1(self) -> Self {
+    if dispatch::has(dispatch::SSE) {
+      return unsafe { rsqrt_nr1_sse(self) };
+    }
+
+    return self.rsqrt();
   }
 }
 
 impl Geometry for float4 {
 }
 
+impl Sort for float4 {
+}
+
 impl float4 {
   #[inline(always)]
   pub fn lo(self) -> float2 {
@@ -163,4 +315,75 @@ impl float4 {
   pub fn even(self) -> float2 {
     return float2(self.0, self.2);
   }
+
+  /// `out[i] = x[i].dot(y[i])` over whole slices, the CPU features are checked once instead of for every element.
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline]
+  pub fn dot_slice(x: &[float4], y: &[float4], out: &mut [f32]) {
+    assert!(x.len() == y.len() && x.len() == out.len(), "slices of different lengths");
+
+    if dispatch::has(dispatch::SSE41) {
+      return unsafe { dot_slice_sse41(x, y, out) };
+    }
+
+    for ((a, b), d) in x.iter().zip(y).zip(out.iter_mut()) {
+      *d = reduce_add(*a * *b);
+    }
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline]
+  pub fn dot_slice(x: &[float4], y: &[float4], out: &mut [f32]) {
+    assert!(x.len() == y.len() && x.len() == out.len(), "slices of different lengths");
+
+    for ((a, b), d) in x.iter().zip(y).zip(out.iter_mut()) {
+      *d = a.dot(*b);
+    }
+  }
+
+  /// `x[i] = x[i].add_mul(a[i], b[i])` over whole slices.
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline]
+  pub fn add_mul_slice(x: &mut [float4], a: &[float4], b: &[float4]) {
+    assert!(x.len() == a.len() && x.len() == b.len(), "slices of different lengths");
+
+    if dispatch::has(dispatch::FMA) {
+      return unsafe { add_mul_slice_fma(x, a, b) };
+    }
+
+    for ((r, a), b) in x.iter_mut().zip(a).zip(b) {
+      *r = *a * *b + *r;
+    }
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline]
+  pub fn add_mul_slice(x: &mut [float4], a: &[float4], b: &[float4]) {
+    assert!(x.len() == a.len() && x.len() == b.len(), "slices of different lengths");
+
+    for ((r, a), b) in x.iter_mut().zip(a).zip(b) {
+      *r = r.add_mul(*a, *b);
+    }
+  }
+
+  /// `x[i] = x[i].rsqrt()` in place over a whole slice.
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline]
+  pub fn rsqrt_slice(x: &mut [float4]) {
+    if dispatch::has(dispatch::SSE) {
+      return unsafe { rsqrt_slice_sse(x) };
+    }
+
+    for r in x.iter_mut() {
+      *r = r.sqrt().recip();
+    }
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline]
+  pub fn rsqrt_slice(x: &mut [float4]) {
+    for r in x.iter_mut() {
+      *r = r.rsqrt();
+    }
+  }
 }
@@ -1,10 +1,126 @@
 use std;
 use ::*;
 
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+extern "platform-intrinsic" {
+  fn x86_mm256_sqrt_ps(x: float8) -> float8;
+  fn x86_mm256_rsqrt_ps(x: float8) -> float8;
+  fn x86_mm256_rcp_ps(x: float8) -> float8;
+  fn x86_mm256_dp_ps(x: float8, y: float8, z: i32) -> float8;
+  fn x86_mm256_max_ps(x: float8, y: float8) -> float8;
+  fn x86_mm256_min_ps(x: float8, y: float8) -> float8;
+  fn x86_mm256_fmadd_ps(x: float8, y: float8, z: float8) -> float8;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn max_avx(x: float8, y: float8) -> float8 {
+  // The x86 instructions return the second operand if either one is NaN, we prefer the number like `f32::max`
+  return y.ne(y).bitselect(x86_mm256_max_ps(x, y), x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn min_avx(x: float8, y: float8) -> float8 {
+  return y.ne(y).bitselect(x86_mm256_min_ps(x, y), x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn reduce_add_avx(x: float8) -> f32 {
+  let x = x + float8(x.4, x.5, x.6, x.7, x.0, x.1, x.2, x.3);
+  let x = x + float8(x.2, x.3, x.0, x.1, x.6, x.7, x.4, x.5);
+  let x = x + float8(x.1, x.0, x.3, x.2, x.5, x.4, x.7, x.6);
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn reduce_max_avx(x: float8) -> f32 {
+  let x = max_avx(x, float8(x.4, x.5, x.6, x.7, x.0, x.1, x.2, x.3));
+  let x = max_avx(x, float8(x.2, x.3, x.0, x.1, x.6, x.7, x.4, x.5));
+  let x = max_avx(x, float8(x.1, x.0, x.3, x.2, x.5, x.4, x.7, x.6));
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn reduce_min_avx(x: float8) -> f32 {
+  let x = min_avx(x, float8(x.4, x.5, x.6, x.7, x.0, x.1, x.2, x.3));
+  let x = min_avx(x, float8(x.2, x.3, x.0, x.1, x.6, x.7, x.4, x.5));
+  let x = min_avx(x, float8(x.1, x.0, x.3, x.2, x.5, x.4, x.7, x.6));
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn dot_avx(x: float8, y: float8) -> f32 {
+  let d = x86_mm256_dp_ps(x, y, 0xF1);
+
+  return d.0 + d.4;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx,+fma"]
+#[inline]
+unsafe fn add_mul_fma(x: float8, a: float8, b: float8) -> float8 {
+  return x86_mm256_fmadd_ps(a, b, x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn sqrt_avx(x: float8) -> float8 {
+  return x86_mm256_sqrt_ps(x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn recip_fast_avx(x: float8) -> float8 {
+  return x86_mm256_rcp_ps(x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn recip_nr1_avx(x: float8) -> float8 {
+  let r = x86_mm256_rcp_ps(x);
+
+  return r * (2.0 - x.eq(float8::broadcast(0.0)).bitselect(x, float8::broadcast(-std::f32::INFINITY)) * r);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn rsqrt_fast_avx(x: float8) -> float8 {
+  return x86_mm256_rsqrt_ps(x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn rsqrt_nr1_avx(x: float8) -> float8 {
+  let r = x86_mm256_rsqrt_ps(x);
+
+  return r * (1.5 - 0.5 * x.eq(float8::broadcast(0.0)).bitselect(x, float8::broadcast(-std::f32::INFINITY)) * r * r);
+}
+
 impl Vector for float8 {
   type Scalar = f32;
   type Boolean = int8;
 
+  const LANES: usize = 8;
+
   type CharVector = char8;
   type ShortVector = short8;
   type IntVector = int8;
@@ -40,6 +156,84 @@ impl Vector for float8 {
     return x.bitselect(Self::from(0), self);
   }
 
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn add_mul(self, a: Self, b: Self) -> Self {
+    if dispatch::has(dispatch::AVX | dispatch::FMA) {
+      return unsafe { add_mul_fma(self, a, b) };
+    }
+
+    return a * b + self;
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn max(self, other: Self) -> Self {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { max_avx(self, other) };
+    }
+
+    return self.map_binary(other, &<f32 as scalar::Scalar>::max);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn min(self, other: Self) -> Self {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { min_avx(self, other) };
+    }
+
+    return self.map_binary(other, &<f32 as scalar::Scalar>::min);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_add(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { reduce_add_avx(self) };
+    }
+
+    return self.reduce_tree(&<f32 as std::ops::Add>::add);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline(always)]
+  fn reduce_add(self) -> Self::Scalar {
+    return self.reduce_tree(&<f32 as std::ops::Add>::add);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_max(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { reduce_max_avx(self) };
+    }
+
+    return self.reduce_tree(&<f32 as scalar::Scalar>::max);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline(always)]
+  fn reduce_max(self) -> Self::Scalar {
+    return self.reduce_tree(&<f32 as scalar::Scalar>::max);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_min(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { reduce_min_avx(self) };
+    }
+
+    return self.reduce_tree(&<f32 as scalar::Scalar>::min);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+  #[inline(always)]
+  fn reduce_min(self) -> Self::Scalar {
+    return self.reduce_tree(&<f32 as scalar::Scalar>::min);
+  }
+
   #[inline(always)]
   fn to_char_sat(self) -> char8 {
     return float8::to_char(self.clamp(Self::broadcast(std::i8::MIN as f32), Self::broadcast(std::i8::MAX as f32)));
@@ -83,6 +277,18 @@ impl Vector for float8 {
 
 impl Dot<float8> for float8 {
   type DotProduct = f32;
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn dot(self, other: Self) -> Self::DotProduct {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { dot_avx(self, other) };
+    }
+
+    return reduce_add(self * other);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
   #[inline(always)]
   fn dot(self, other: Self) -> Self::DotProduct {
     return reduce_add(self * other);
@@ -93,11 +299,85 @@ impl Float for float8 {
   type FloatScalar = f32;
 
   const SIGN_MASK: i32 = std::i32::MAX;
+  const EXPONENT_MASK: i32 = 0x7F800000;
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn fma(self, b: Self, c: Self) -> Self {
+    if dispatch::has(dispatch::AVX | dispatch::FMA) {
+      return unsafe { add_mul_fma(c, self, b) };
+    }
+
+    return super::fma_lanes(self, b, c);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn sqrt(self) -> Self {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { sqrt_avx(self) };
+    }
+
+    return self.map_unary(&<f32 as scalar::FloatScalar>::sqrt);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn recip_fast(self) -> Self {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { recip_fast_avx(self) };
+    }
+
+    return self.recip();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn recip_nr1(self) -> Self {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { recip_nr1_avx(self) };
+    }
+
+    return self.recip();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt(self) -> Self {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { rsqrt_nr1_avx(self) };
+    }
+
+    return self.sqrt().recip();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt_fast(self) -> Self {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { rsqrt_fast_avx(self) };
+    }
+
+    return self.rsqrt();
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn rsqrt_nr1(self) -> Self {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { rsqrt_nr1_avx(self) };
+    }
+
+    return self.rsqrt();
+  }
 }
 
 impl Geometry for float8 {
 }
 
+impl Sort for float8 {
+}
+
 impl float8 {
   #[inline(always)]
   pub fn lo(self) -> float4 {
@@ -5,6 +5,8 @@ impl Vector for int16 {
   type Scalar = i32;
   type Boolean = int16;
 
+  const LANES: usize = 16;
+
   type CharVector = char16;
   type ShortVector = short16;
   type IntVector = int16;
@@ -122,6 +124,12 @@ impl Select<float16> for int16 {
   }
 }
 
+impl Mask for int16 {
+}
+
+impl Sort for int16 {
+}
+
 impl int16 {
   #[inline(always)]
   pub fn lo(self) -> int8 {
@@ -5,6 +5,8 @@ impl Vector for int2 {
   type Scalar = i32;
   type Boolean = int2;
 
+  const LANES: usize = 2;
+
   type CharVector = char2;
   type ShortVector = short2;
   type IntVector = int2;
@@ -122,6 +124,9 @@ impl Select<float2> for int2 {
   }
 }
 
+impl Mask for int2 {
+}
+
 impl int2 {
   #[inline(always)]
   pub fn lo(self) -> i32 {
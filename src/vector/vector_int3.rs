@@ -5,6 +5,8 @@ impl Vector for int3 {
   type Scalar = i32;
   type Boolean = int3;
 
+  const LANES: usize = 3;
+
   type CharVector = char3;
   type ShortVector = short3;
   type IntVector = int3;
@@ -137,6 +139,9 @@ impl Select<float3> for int3 {
   }
 }
 
+impl Mask for int3 {
+}
+
 impl int3 {
   #[inline(always)]
   pub fn lo(self) -> int2 {
@@ -1,10 +1,24 @@
 use std;
 use ::*;
 
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+extern "platform-intrinsic" {
+  fn x86_mm_movemask_ps(x: float4) -> i32;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+sse"]
+#[inline]
+unsafe fn to_bitmask_sse(x: int4) -> u16 {
+  return x86_mm_movemask_ps(float4::bitcast(x)) as u16;
+}
+
 impl Vector for int4 {
   type Scalar = i32;
   type Boolean = int4;
 
+  const LANES: usize = 4;
+
   type CharVector = char4;
   type ShortVector = short4;
   type IntVector = int4;
@@ -122,6 +136,21 @@ impl Select<float4> for int4 {
   }
 }
 
+impl Mask for int4 {
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn to_bitmask(self) -> u16 {
+    if dispatch::has(dispatch::SSE) {
+      return unsafe { to_bitmask_sse(self) };
+    }
+
+    return super::to_bitmask_lanes(self);
+  }
+}
+
+impl Sort for int4 {
+}
+
 impl int4 {
   #[inline(always)]
   pub fn lo(self) -> int2 {
@@ -1,10 +1,92 @@
 use std;
 use ::*;
 
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+extern "platform-intrinsic" {
+  fn x86_mm256_abs_epi32(x: int8) -> int8;
+  fn x86_mm256_max_epi32(x: int8, y: int8) -> int8;
+  fn x86_mm256_min_epi32(x: int8, y: int8) -> int8;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx2"]
+#[inline]
+unsafe fn max_avx2(x: int8, y: int8) -> int8 {
+  return x86_mm256_max_epi32(x, y);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx2"]
+#[inline]
+unsafe fn min_avx2(x: int8, y: int8) -> int8 {
+  return x86_mm256_min_epi32(x, y);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx2"]
+#[inline]
+unsafe fn abs_avx2(x: int8) -> int8 {
+  return x86_mm256_abs_epi32(x);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx2"]
+#[inline]
+unsafe fn reduce_add_avx2(x: int8) -> i32 {
+  let x = x + int8(x.4, x.5, x.6, x.7, x.0, x.1, x.2, x.3);
+  let x = x + int8(x.2, x.3, x.0, x.1, x.6, x.7, x.4, x.5);
+  let x = x + int8(x.1, x.0, x.3, x.2, x.5, x.4, x.7, x.6);
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx2"]
+#[inline]
+unsafe fn reduce_max_avx2(x: int8) -> i32 {
+  let x = max_avx2(x, int8(x.4, x.5, x.6, x.7, x.0, x.1, x.2, x.3));
+  let x = max_avx2(x, int8(x.2, x.3, x.0, x.1, x.6, x.7, x.4, x.5));
+  let x = max_avx2(x, int8(x.1, x.0, x.3, x.2, x.5, x.4, x.7, x.6));
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx2"]
+#[inline]
+unsafe fn reduce_min_avx2(x: int8) -> i32 {
+  let x = min_avx2(x, int8(x.4, x.5, x.6, x.7, x.0, x.1, x.2, x.3));
+  let x = min_avx2(x, int8(x.2, x.3, x.0, x.1, x.6, x.7, x.4, x.5));
+  let x = min_avx2(x, int8(x.1, x.0, x.3, x.2, x.5, x.4, x.7, x.6));
+
+  return x.0;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx2"]
+#[inline]
+unsafe fn dot_avx2(x: int8, y: int8) -> i32 {
+  return reduce_add_avx2(x * y);
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+extern "platform-intrinsic" {
+  fn x86_mm256_movemask_ps(x: float8) -> i32;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn to_bitmask_avx(x: int8) -> u16 {
+  return x86_mm256_movemask_ps(float8::bitcast(x)) as u16;
+}
+
 impl Vector for int8 {
   type Scalar = i32;
   type Boolean = int8;
 
+  const LANES: usize = 8;
+
   type CharVector = char8;
   type ShortVector = short8;
   type IntVector = int8;
@@ -33,6 +115,19 @@ impl Vector for int8 {
     return f(self.7, f(self.6, f(self.5, f(self.4, f(self.3, f(self.2, f(self.1, self.0)))))));
   }
 
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn abs(self) -> Self {
+    if dispatch::has(dispatch::AVX2) {
+      return unsafe { abs_avx2(self) };
+    }
+
+    let mask = self >> 31;
+
+    return (self ^ mask) - mask;
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
   #[inline(always)]
   fn abs(self) -> Self {
     let mask = self >> 31;
@@ -40,6 +135,56 @@ impl Vector for int8 {
     return (self ^ mask) - mask;
   }
 
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn max(self, other: Self) -> Self {
+    if dispatch::has(dispatch::AVX2) {
+      return unsafe { max_avx2(self, other) };
+    }
+
+    return self.map_binary(other, &<i32 as scalar::Scalar>::max);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn min(self, other: Self) -> Self {
+    if dispatch::has(dispatch::AVX2) {
+      return unsafe { min_avx2(self, other) };
+    }
+
+    return self.map_binary(other, &<i32 as scalar::Scalar>::min);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_add(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX2) {
+      return unsafe { reduce_add_avx2(self) };
+    }
+
+    return self.reduce(&<i32 as std::ops::Add>::add);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_max(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX2) {
+      return unsafe { reduce_max_avx2(self) };
+    }
+
+    return self.reduce(&<i32 as scalar::Scalar>::max);
+  }
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn reduce_min(self) -> Self::Scalar {
+    if dispatch::has(dispatch::AVX2) {
+      return unsafe { reduce_min_avx2(self) };
+    }
+
+    return self.reduce(&<i32 as scalar::Scalar>::min);
+  }
+
   #[inline(always)]
   fn to_char_sat(self) -> char8 {
     return int8::to_char(self.clamp(Self::broadcast(std::i8::MIN as i32), Self::broadcast(std::i8::MAX as i32)));
@@ -83,6 +228,18 @@ impl Vector for int8 {
 
 impl Dot<int8> for int8 {
   type DotProduct = i32;
+
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn dot(self, other: Self) -> Self::DotProduct {
+    if dispatch::has(dispatch::AVX2) {
+      return unsafe { dot_avx2(self, other) };
+    }
+
+    return reduce_add(self * other);
+  }
+
+  #[cfg(any(feature = "portable", not(any(target_arch = "x86", target_arch = "x86_64"))))]
   #[inline(always)]
   fn dot(self, other: Self) -> Self::DotProduct {
     return reduce_add(self * other);
@@ -122,6 +279,21 @@ impl Select<float8> for int8 {
   }
 }
 
+impl Mask for int8 {
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn to_bitmask(self) -> u16 {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { to_bitmask_avx(self) };
+    }
+
+    return self.lo().to_bitmask() | (self.hi().to_bitmask() << 4);
+  }
+}
+
+impl Sort for int8 {
+}
+
 impl int8 {
   #[inline(always)]
   pub fn lo(self) -> int4 {
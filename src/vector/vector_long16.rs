@@ -5,6 +5,8 @@ impl Vector for long16 {
   type Scalar = i64;
   type Boolean = long16;
 
+  const LANES: usize = 16;
+
   type CharVector = char16;
   type ShortVector = short16;
   type IntVector = int16;
@@ -122,6 +124,12 @@ impl Select<double16> for long16 {
   }
 }
 
+impl Mask for long16 {
+}
+
+impl Sort for long16 {
+}
+
 impl long16 {
   #[inline(always)]
   pub fn lo(self) -> long8 {
@@ -1,10 +1,17 @@
 use std;
 use ::*;
 
+#[cfg(all(not(feature = "portable"), target_feature = "sse2"))]
+extern "platform-intrinsic" {
+  fn x86_mm_movemask_pd(x: double2) -> i32;
+}
+
 impl Vector for long2 {
   type Scalar = i64;
   type Boolean = long2;
 
+  const LANES: usize = 2;
+
   type CharVector = char2;
   type ShortVector = short2;
   type IntVector = int2;
@@ -122,6 +129,14 @@ impl Select<double2> for long2 {
   }
 }
 
+impl Mask for long2 {
+  #[cfg(all(not(feature = "portable"), target_feature = "sse2"))]
+  #[inline(always)]
+  fn to_bitmask(self) -> u16 {
+    return unsafe { x86_mm_movemask_pd(double2::bitcast(self)) as u16 };
+  }
+}
+
 impl long2 {
   #[inline(always)]
   pub fn lo(self) -> i64 {
@@ -5,6 +5,8 @@ impl Vector for long3 {
   type Scalar = i64;
   type Boolean = long3;
 
+  const LANES: usize = 3;
+
   type CharVector = char3;
   type ShortVector = short3;
   type IntVector = int3;
@@ -122,6 +124,9 @@ impl Select<double3> for long3 {
   }
 }
 
+impl Mask for long3 {
+}
+
 impl long3 {
   #[inline(always)]
   pub fn lo(self) -> long2 {
@@ -1,10 +1,24 @@
 use std;
 use ::*;
 
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+extern "platform-intrinsic" {
+  fn x86_mm256_movemask_pd(x: double4) -> i32;
+}
+
+#[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature = "+avx"]
+#[inline]
+unsafe fn to_bitmask_avx(x: long4) -> u16 {
+  return x86_mm256_movemask_pd(double4::bitcast(x)) as u16;
+}
+
 impl Vector for long4 {
   type Scalar = i64;
   type Boolean = long4;
 
+  const LANES: usize = 4;
+
   type CharVector = char4;
   type ShortVector = short4;
   type IntVector = int4;
@@ -122,6 +136,21 @@ impl Select<double4> for long4 {
   }
 }
 
+impl Mask for long4 {
+  #[cfg(all(not(feature = "portable"), any(target_arch = "x86", target_arch = "x86_64")))]
+  #[inline(always)]
+  fn to_bitmask(self) -> u16 {
+    if dispatch::has(dispatch::AVX) {
+      return unsafe { to_bitmask_avx(self) };
+    }
+
+    return self.lo().to_bitmask() | (self.hi().to_bitmask() << 2);
+  }
+}
+
+impl Sort for long4 {
+}
+
 impl long4 {
   #[inline(always)]
   pub fn lo(self) -> long2 {
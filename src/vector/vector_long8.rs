@@ -5,6 +5,8 @@ impl Vector for long8 {
   type Scalar = i64;
   type Boolean = long8;
 
+  const LANES: usize = 8;
+
   type CharVector = char8;
   type ShortVector = short8;
   type IntVector = int8;
@@ -122,6 +124,12 @@ impl Select<double8> for long8 {
   }
 }
 
+impl Mask for long8 {
+}
+
+impl Sort for long8 {
+}
+
 impl long8 {
   #[inline(always)]
   pub fn lo(self) -> long4 {
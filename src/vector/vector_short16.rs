@@ -5,6 +5,8 @@ impl Vector for short16 {
   type Scalar = i16;
   type Boolean = short16;
 
+  const LANES: usize = 16;
+
   type CharVector = char16;
   type ShortVector = short16;
   type IntVector = int16;
@@ -113,6 +115,12 @@ impl Select<ushort16> for short16 {
   }
 }
 
+impl Mask for short16 {
+}
+
+impl Sort for short16 {
+}
+
 impl short16 {
   #[inline(always)]
   pub fn lo(self) -> short8 {
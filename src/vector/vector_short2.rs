@@ -5,6 +5,8 @@ impl Vector for short2 {
   type Scalar = i16;
   type Boolean = short2;
 
+  const LANES: usize = 2;
+
   type CharVector = char2;
   type ShortVector = short2;
   type IntVector = int2;
@@ -113,6 +115,9 @@ impl Select<ushort2> for short2 {
   }
 }
 
+impl Mask for short2 {
+}
+
 impl short2 {
   #[inline(always)]
   pub fn lo(self) -> i16 {
@@ -5,6 +5,8 @@ impl Vector for short3 {
   type Scalar = i16;
   type Boolean = short3;
 
+  const LANES: usize = 3;
+
   type CharVector = char3;
   type ShortVector = short3;
   type IntVector = int3;
@@ -143,6 +145,9 @@ impl Select<ushort3> for short3 {
   }
 }
 
+impl Mask for short3 {
+}
+
 impl short3 {
   #[inline(always)]
   pub fn lo(self) -> short2 {
@@ -5,6 +5,8 @@ impl Vector for short4 {
   type Scalar = i16;
   type Boolean = short4;
 
+  const LANES: usize = 4;
+
   type CharVector = char4;
   type ShortVector = short4;
   type IntVector = int4;
@@ -113,6 +115,12 @@ impl Select<ushort4> for short4 {
   }
 }
 
+impl Mask for short4 {
+}
+
+impl Sort for short4 {
+}
+
 impl short4 {
   #[inline(always)]
   pub fn lo(self) -> short2 {
@@ -5,6 +5,8 @@ impl Vector for short8 {
   type Scalar = i16;
   type Boolean = short8;
 
+  const LANES: usize = 8;
+
   type CharVector = char8;
   type ShortVector = short8;
   type IntVector = int8;
@@ -113,6 +115,12 @@ impl Select<ushort8> for short8 {
   }
 }
 
+impl Mask for short8 {
+}
+
+impl Sort for short8 {
+}
+
 impl short8 {
   #[inline(always)]
   pub fn lo(self) -> short4 {
@@ -5,6 +5,8 @@ impl Vector for uchar16 {
   type Scalar = u8;
   type Boolean = char16;
 
+  const LANES: usize = 16;
+
   type CharVector = char16;
   type ShortVector = short16;
   type IntVector = int16;
@@ -93,6 +95,9 @@ impl Integer for uchar16 {
   const SIGN_MASK: u8 = 0x80;
 }
 
+impl Sort for uchar16 {
+}
+
 impl uchar16 {
   #[inline(always)]
   pub fn lo(self) -> uchar8 {
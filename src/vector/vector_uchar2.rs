@@ -5,6 +5,8 @@ impl Vector for uchar2 {
   type Scalar = u8;
   type Boolean = char2;
 
+  const LANES: usize = 2;
+
   type CharVector = char2;
   type ShortVector = short2;
   type IntVector = int2;
@@ -5,6 +5,8 @@ impl Vector for uchar3 {
   type Scalar = u8;
   type Boolean = char3;
 
+  const LANES: usize = 3;
+
   type CharVector = char3;
   type ShortVector = short3;
   type IntVector = int3;
@@ -5,6 +5,8 @@ impl Vector for uchar4 {
   type Scalar = u8;
   type Boolean = char4;
 
+  const LANES: usize = 4;
+
   type CharVector = char4;
   type ShortVector = short4;
   type IntVector = int4;
@@ -93,6 +95,9 @@ impl Integer for uchar4 {
   const SIGN_MASK: u8 = 0x80;
 }
 
+impl Sort for uchar4 {
+}
+
 impl uchar4 {
   #[inline(always)]
   pub fn lo(self) -> uchar2 {
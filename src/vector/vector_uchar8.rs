@@ -5,6 +5,8 @@ impl Vector for uchar8 {
   type Scalar = u8;
   type Boolean = char8;
 
+  const LANES: usize = 8;
+
   type CharVector = char8;
   type ShortVector = short8;
   type IntVector = int8;
@@ -93,6 +95,9 @@ impl Integer for uchar8 {
   const SIGN_MASK: u8 = 0x80;
 }
 
+impl Sort for uchar8 {
+}
+
 impl uchar8 {
   #[inline(always)]
   pub fn lo(self) -> uchar4 {
@@ -5,6 +5,8 @@ impl Vector for uint16 {
   type Scalar = u32;
   type Boolean = int16;
 
+  const LANES: usize = 16;
+
   type CharVector = char16;
   type ShortVector = short16;
   type IntVector = int16;
@@ -93,6 +95,9 @@ impl Integer for uint16 {
   const SIGN_MASK: u32 = 0x80000000;
 }
 
+impl Sort for uint16 {
+}
+
 impl uint16 {
   #[inline(always)]
   pub fn lo(self) -> uint8 {
@@ -5,6 +5,8 @@ impl Vector for uint2 {
   type Scalar = u32;
   type Boolean = int2;
 
+  const LANES: usize = 2;
+
   type CharVector = char2;
   type ShortVector = short2;
   type IntVector = int2;
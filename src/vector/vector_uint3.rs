@@ -5,6 +5,8 @@ impl Vector for uint3 {
   type Scalar = u32;
   type Boolean = int3;
 
+  const LANES: usize = 3;
+
   type CharVector = char3;
   type ShortVector = short3;
   type IntVector = int3;
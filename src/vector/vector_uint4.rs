@@ -5,6 +5,8 @@ impl Vector for uint4 {
   type Scalar = u32;
   type Boolean = int4;
 
+  const LANES: usize = 4;
+
   type CharVector = char4;
   type ShortVector = short4;
   type IntVector = int4;
@@ -93,6 +95,9 @@ impl Integer for uint4 {
   const SIGN_MASK: u32 = 0x80000000;
 }
 
+impl Sort for uint4 {
+}
+
 impl uint4 {
   #[inline(always)]
   pub fn lo(self) -> uint2 {
@@ -5,6 +5,8 @@ impl Vector for uint8 {
   type Scalar = u32;
   type Boolean = int8;
 
+  const LANES: usize = 8;
+
   type CharVector = char8;
   type ShortVector = short8;
   type IntVector = int8;
@@ -93,6 +95,9 @@ impl Integer for uint8 {
   const SIGN_MASK: u32 = 0x80000000;
 }
 
+impl Sort for uint8 {
+}
+
 impl uint8 {
   #[inline(always)]
   pub fn lo(self) -> uint4 {
@@ -5,6 +5,8 @@ impl Vector for ulong16 {
   type Scalar = u64;
   type Boolean = long16;
 
+  const LANES: usize = 16;
+
   type CharVector = char16;
   type ShortVector = short16;
   type IntVector = int16;
@@ -93,6 +95,9 @@ impl Integer for ulong16 {
   const SIGN_MASK: u64 = 0x8000000000000000;
 }
 
+impl Sort for ulong16 {
+}
+
 impl ulong16 {
   #[inline(always)]
   pub fn lo(self) -> ulong8 {
@@ -5,6 +5,8 @@ impl Vector for ulong2 {
   type Scalar = u64;
   type Boolean = long2;
 
+  const LANES: usize = 2;
+
   type CharVector = char2;
   type ShortVector = short2;
   type IntVector = int2;
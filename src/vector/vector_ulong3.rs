@@ -5,6 +5,8 @@ impl Vector for ulong3 {
   type Scalar = u64;
   type Boolean = long3;
 
+  const LANES: usize = 3;
+
   type CharVector = char3;
   type ShortVector = short3;
   type IntVector = int3;
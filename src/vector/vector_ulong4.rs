@@ -5,6 +5,8 @@ impl Vector for ulong4 {
   type Scalar = u64;
   type Boolean = long4;
 
+  const LANES: usize = 4;
+
   type CharVector = char4;
   type ShortVector = short4;
   type IntVector = int4;
@@ -93,6 +95,9 @@ impl Integer for ulong4 {
   const SIGN_MASK: u64 = 0x8000000000000000;
 }
 
+impl Sort for ulong4 {
+}
+
 impl ulong4 {
   #[inline(always)]
   pub fn lo(self) -> ulong2 {
@@ -5,6 +5,8 @@ impl Vector for ulong8 {
   type Scalar = u64;
   type Boolean = long8;
 
+  const LANES: usize = 8;
+
   type CharVector = char8;
   type ShortVector = short8;
   type IntVector = int8;
@@ -93,6 +95,9 @@ impl Integer for ulong8 {
   const SIGN_MASK: u64 = 0x8000000000000000;
 }
 
+impl Sort for ulong8 {
+}
+
 impl ulong8 {
   #[inline(always)]
   pub fn lo(self) -> ulong4 {
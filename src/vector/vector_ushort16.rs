@@ -5,6 +5,8 @@ impl Vector for ushort16 {
   type Scalar = u16;
   type Boolean = short16;
 
+  const LANES: usize = 16;
+
   type CharVector = char16;
   type ShortVector = short16;
   type IntVector = int16;
@@ -93,6 +95,9 @@ impl Integer for ushort16 {
   const SIGN_MASK: u16 = 0x8000;
 }
 
+impl Sort for ushort16 {
+}
+
 impl ushort16 {
   #[inline(always)]
   pub fn lo(self) -> ushort8 {
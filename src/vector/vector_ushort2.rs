@@ -5,6 +5,8 @@ impl Vector for ushort2 {
   type Scalar = u16;
   type Boolean = short2;
 
+  const LANES: usize = 2;
+
   type CharVector = char2;
   type ShortVector = short2;
   type IntVector = int2;
@@ -5,6 +5,8 @@ impl Vector for ushort3 {
   type Scalar = u16;
   type Boolean = short3;
 
+  const LANES: usize = 3;
+
   type CharVector = char3;
   type ShortVector = short3;
   type IntVector = int3;
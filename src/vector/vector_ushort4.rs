@@ -5,6 +5,8 @@ impl Vector for ushort4 {
   type Scalar = u16;
   type Boolean = short4;
 
+  const LANES: usize = 4;
+
   type CharVector = char4;
   type ShortVector = short4;
   type IntVector = int4;
@@ -93,6 +95,9 @@ impl Integer for ushort4 {
   const SIGN_MASK: u16 = 0x8000;
 }
 
+impl Sort for ushort4 {
+}
+
 impl ushort4 {
   #[inline(always)]
   pub fn lo(self) -> ushort2 {
@@ -5,6 +5,8 @@ impl Vector for ushort8 {
   type Scalar = u16;
   type Boolean = short8;
 
+  const LANES: usize = 8;
+
   type CharVector = char8;
   type ShortVector = short8;
   type IntVector = int8;
@@ -93,6 +95,9 @@ impl Integer for ushort8 {
   const SIGN_MASK: u16 = 0x8000;
 }
 
+impl Sort for ushort8 {
+}
+
 impl ushort8 {
   #[inline(always)]
   pub fn lo(self) -> ushort4 {
@@ -0,0 +1,56 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+#[test]
+fn test_vector() {
+  let x = float4(1.0, 100.0, -0.0, std::f32::INFINITY);
+  let y = float4(1.001, 100.01, 0.0, std::f32::INFINITY);
+
+  assert_eq!(x.approx_eq_abs(y, 0.005), int4(-1, 0, -1, -1));
+  assert_eq!(x.approx_eq_rel(y, 0.0002), int4(0, -1, -1, -1));
+
+  assert!(almost_equal_elements(x, y, 0.05));
+  assert!(!almost_equal_elements_relative(x, y, 1e-6));
+
+  assert_eq!(float2(std::f32::INFINITY, 1.0).approx_eq_rel(float2(1.0, 1.0), 1e-6), int2(0, -1));
+  assert_eq!(double2(-std::f64::INFINITY, 1.0).approx_eq_rel(double2(std::f64::MAX, 1.0), 2.0), long2(0, -1));
+
+  let nan = double2(std::f64::NAN, 1.0);
+  assert_eq!(nan.approx_eq_abs(nan, 1.0), long2(0, -1));
+}
+
+#[test]
+fn test_ulps() {
+  let one = 1.0f32;
+  let next = f32::from_bits(one.to_bits() + 2);
+
+  assert_eq!(float2(one, one).approx_eq_ulps(float2(next, -next), 2), int2(-1, 0));
+  assert_eq!(float2(one, 0.0).approx_eq_ulps(float2(next, -0.0), 1), int2(0, -1));
+  assert!(!almost_equal_elements_ulps(double2(std::f64::MAX, -1.0), double2(std::f64::INFINITY, -1.0), 1));
+  assert_eq!(float2(std::f32::INFINITY, -std::f32::INFINITY).approx_eq_ulps(float2(std::f32::INFINITY, -std::f32::INFINITY), 0), int2(-1, -1));
+  assert_eq!(float2(std::f32::MAX, 1.0).approx_eq_ulps(float2(std::f32::INFINITY, 1.0), std::u32::MAX), int2(0, -1));
+  assert!(!almost_equal_elements_ulps(float2(std::f32::NAN, 0.0), float2(std::f32::NAN, 0.0), 10));
+
+  assert_eq!(float2(1.0, 2.0).approx_eq_ulps(float2(1.0, 2.5), std::u32::MAX), int2(-1, -1));
+  assert_eq!(float2(1.0, std::f32::MAX).approx_eq_ulps(float2(-1.0, 0.0), std::u32::MAX), int2(0, -1));
+}
+
+#[test]
+fn test_matrix() {
+  let x = float3x2(float2(1.0, 2.0), float2(3.0, 4.0), float2(5.0, 6.0));
+  let y = x * 1.00001;
+
+  assert_eq!(x.approx_eq_rel(y, 1e-6), [int2(0, 0), int2(0, 0), int2(0, 0)]);
+  assert_eq!(x.approx_eq_abs(y, 2.5e-5), [int2(-1, -1), int2(0, 0), int2(0, 0)]);
+
+  assert_approx_eq!(x, y, 1e-4);
+  assert_approx_eq_relative!(x, y, 1e-4);
+  assert_approx_eq_ulps!(double2x2::identity(), double2x2::identity() * (1.0 + std::f64::EPSILON), 1);
+}
+
+#[test]
+#[should_panic(expected = "epsilon: `0.001`")]
+fn test_assert_approx_eq() {
+  assert_approx_eq!(float2(1.0, 2.0), float2(1.0, 2.1), 0.001);
+}
@@ -0,0 +1,129 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+#[inline(never)]
+fn abs(x: double4) -> double4 {
+  return x.abs();
+}
+
+#[inline(never)]
+fn add(x: double4, y: double4) -> double4 {
+  return x + y;
+}
+
+#[inline(never)]
+fn sub(x: double4, y: double4) -> double4 {
+  return x - y;
+}
+
+#[inline(never)]
+fn mul(x: double4, y: double4) -> double4 {
+  return x * y;
+}
+
+#[inline(never)]
+fn div(x: double4, y: double4) -> double4 {
+  return x / y;
+}
+
+#[inline(never)]
+fn sqrt(x: double4) -> double4 {
+  return x.sqrt();
+}
+
+#[inline(never)]
+fn rsqrt(x: double4) -> double4 {
+  return x.rsqrt();
+}
+
+#[inline(never)]
+fn add_mul(x: double4, y: double4, z: double4) -> double4 {
+  return x.add_mul(y, z);
+}
+
+#[inline(never)]
+fn dot(x: double4, y: double4) -> f64 {
+  return x.dot(y);
+}
+
+#[inline(never)]
+fn max(x: double4, y: double4) -> double4 {
+  return x.max(y);
+}
+
+#[inline(never)]
+fn min(x: double4, y: double4) -> double4 {
+  return x.min(y);
+}
+
+#[inline(never)]
+fn reduce_add(x: double4) -> f64 {
+  return x.reduce_add();
+}
+
+#[inline(never)]
+fn reduce_max(x: double4) -> f64 {
+  return x.reduce_max();
+}
+
+#[inline(never)]
+fn reduce_min(x: double4) -> f64 {
+  return x.reduce_min();
+}
+
+#[test]
+fn test() {
+  let a = double4(1.0, 2.0, 3.0, 4.0);
+  let b = double4(2.0, 3.0, 4.0, 5.0);
+  let c = double4(-1.0, -2.0, -3.0, -4.0);
+  let z = double4::broadcast(0.0);
+
+  assert_eq!(abs(a), a);
+  assert_eq!(abs(b), b);
+  assert_eq!(abs(c), a);
+
+  assert_eq!(dot(a, b), 40.0);
+  assert_eq!(dot(b, a), 40.0);
+
+  assert_eq!(add(a, b), double4(3.0, 5.0, 7.0, 9.0));
+  assert_eq!(add(b, a), double4(3.0, 5.0, 7.0, 9.0));
+
+  assert_eq!(sub(a, b), double4(-1.0, -1.0, -1.0, -1.0));
+  assert_eq!(sub(b, a), double4(1.0, 1.0, 1.0, 1.0));
+
+  assert_eq!(mul(a, b), double4(2.0, 6.0, 12.0, 20.0));
+  assert_eq!(mul(b, a), double4(2.0, 6.0, 12.0, 20.0));
+
+  assert_eq!(div(a, b), a / b);
+  assert_eq!(div(b, a), b / a);
+
+  assert_eq!(sqrt(a * a), a);
+  assert_eq!(sqrt(b * b), b);
+
+  assert_eq!(rsqrt(a * a), 1.0 / a);
+  assert_eq!(rsqrt(z), 1.0 / z);
+
+  assert_eq!(add_mul(a, b, c), double4(-1.0, -4.0, -9.0, -16.0));
+  assert_eq!(add_mul(b, a, c), double4(1.0, -1.0, -5.0, -11.0));
+
+  assert_eq!(max(a, b), b);
+  assert_eq!(max(b, a), b);
+  assert_eq!(max(a, c), a);
+
+  assert_eq!(min(a, b), a);
+  assert_eq!(min(b, a), a);
+  assert_eq!(min(a, c), c);
+
+  assert_eq!(max(a, double4::broadcast(std::f64::NAN)), a);
+  assert_eq!(min(double4::broadcast(std::f64::NAN), a), a);
+
+  assert_eq!(reduce_add(a), 10.0);
+  assert_eq!(reduce_add(c), -10.0);
+
+  assert_eq!(reduce_max(a), 4.0);
+  assert_eq!(reduce_max(c), -1.0);
+
+  assert_eq!(reduce_min(a), 1.0);
+  assert_eq!(reduce_min(c), -4.0);
+}
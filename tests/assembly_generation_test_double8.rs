@@ -0,0 +1,130 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+#[inline(never)]
+fn abs(x: double8) -> double8 {
+  return x.abs();
+}
+
+#[inline(never)]
+fn add(x: double8, y: double8) -> double8 {
+  return x + y;
+}
+
+#[inline(never)]
+fn sub(x: double8, y: double8) -> double8 {
+  return x - y;
+}
+
+#[inline(never)]
+fn mul(x: double8, y: double8) -> double8 {
+  return x * y;
+}
+
+#[inline(never)]
+fn div(x: double8, y: double8) -> double8 {
+  return x / y;
+}
+
+#[inline(never)]
+fn sqrt(x: double8) -> double8 {
+  return x.sqrt();
+}
+
+#[inline(never)]
+fn rsqrt(x: double8) -> double8 {
+  return x.rsqrt();
+}
+
+#[inline(never)]
+fn add_mul(x: double8, y: double8, z: double8) -> double8 {
+  return x.add_mul(y, z);
+}
+
+#[inline(never)]
+fn dot(x: double8, y: double8) -> f64 {
+  return x.dot(y);
+}
+
+#[inline(never)]
+fn max(x: double8, y: double8) -> double8 {
+  return x.max(y);
+}
+
+#[inline(never)]
+fn min(x: double8, y: double8) -> double8 {
+  return x.min(y);
+}
+
+#[inline(never)]
+fn reduce_add(x: double8) -> f64 {
+  return x.reduce_add();
+}
+
+#[inline(never)]
+fn reduce_max(x: double8) -> f64 {
+  return x.reduce_max();
+}
+
+#[inline(never)]
+fn reduce_min(x: double8) -> f64 {
+  return x.reduce_min();
+}
+
+#[test]
+fn test() {
+  let a = double8(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+  let b = double8(2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+  let c = double8(-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0);
+  let z = double8::broadcast(0.0);
+
+  assert_eq!(abs(a), a);
+  assert_eq!(abs(b), b);
+  assert_eq!(abs(c), a);
+
+  assert_eq!(dot(a, b), 240.0);
+  assert_eq!(dot(b, a), 240.0);
+
+  assert_eq!(add(a, b), double8(3.0, 5.0, 7.0, 9.0, 11.0, 13.0, 15.0, 17.0));
+  assert_eq!(add(b, a), double8(3.0, 5.0, 7.0, 9.0, 11.0, 13.0, 15.0, 17.0));
+
+  assert_eq!(sub(a, b), double8(-1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0));
+  assert_eq!(sub(b, a), double8(1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0));
+
+  assert_eq!(mul(a, b), double8(2.0, 6.0, 12.0, 20.0, 30.0, 42.0, 56.0, 72.0));
+  assert_eq!(mul(b, a), double8(2.0, 6.0, 12.0, 20.0, 30.0, 42.0, 56.0, 72.0));
+
+  assert_eq!(div(a, b), a / b);
+  assert_eq!(div(b, a), b / a);
+
+  assert_eq!(sqrt(a * a), a);
+  assert_eq!(sqrt(b * b), b);
+
+  assert_approx_eq_ulps!(rsqrt(a * a), 1.0 / a, 3);
+  assert_approx_eq_ulps!(rsqrt(b), 1.0 / b.sqrt(), 3);
+  assert_eq!(rsqrt(z), 1.0 / z);
+
+  assert_eq!(add_mul(a, b, c), double8(-1.0, -4.0, -9.0, -16.0, -25.0, -36.0, -49.0, -64.0));
+  assert_eq!(add_mul(b, a, c), double8(1.0, -1.0, -5.0, -11.0, -19.0, -29.0, -41.0, -55.0));
+
+  assert_eq!(max(a, b), b);
+  assert_eq!(max(b, a), b);
+  assert_eq!(max(a, c), a);
+
+  assert_eq!(min(a, b), a);
+  assert_eq!(min(b, a), a);
+  assert_eq!(min(a, c), c);
+
+  assert_eq!(max(a, double8::broadcast(std::f64::NAN)), a);
+  assert_eq!(min(double8::broadcast(std::f64::NAN), a), a);
+
+  assert_eq!(reduce_add(a), 36.0);
+  assert_eq!(reduce_add(c), -36.0);
+
+  assert_eq!(reduce_max(a), 8.0);
+  assert_eq!(reduce_max(c), -1.0);
+
+  assert_eq!(reduce_min(a), 1.0);
+  assert_eq!(reduce_min(c), -8.0);
+}
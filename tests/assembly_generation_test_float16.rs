@@ -0,0 +1,163 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+#[macro_use] mod macros;
+
+#[inline(never)]
+fn abs(x: float16) -> float16 {
+  return x.abs();
+}
+
+#[inline(never)]
+fn add(x: float16, y: float16) -> float16 {
+  return x + y;
+}
+
+#[inline(never)]
+fn sub(x: float16, y: float16) -> float16 {
+  return x - y;
+}
+
+#[inline(never)]
+fn mul(x: float16, y: float16) -> float16 {
+  return x * y;
+}
+
+#[inline(never)]
+fn div(x: float16, y: float16) -> float16 {
+  return x / y;
+}
+
+#[inline(never)]
+fn sqrt(x: float16) -> float16 {
+  return x.sqrt();
+}
+
+#[inline(never)]
+fn rsqrt(x: float16) -> float16 {
+  return x.rsqrt();
+}
+
+#[inline(never)]
+fn recip_fast(x: float16) -> float16 {
+  return x.recip_fast();
+}
+
+#[inline(never)]
+fn recip_nr1(x: float16) -> float16 {
+  return x.recip_nr1();
+}
+
+#[inline(never)]
+fn rsqrt_fast(x: float16) -> float16 {
+  return x.rsqrt_fast();
+}
+
+#[inline(never)]
+fn rsqrt_nr1(x: float16) -> float16 {
+  return x.rsqrt_nr1();
+}
+
+#[inline(never)]
+fn add_mul(x: float16, y: float16, z: float16) -> float16 {
+  return x.add_mul(y, z);
+}
+
+#[inline(never)]
+fn dot(x: float16, y: float16) -> f32 {
+  return x.dot(y);
+}
+
+#[inline(never)]
+fn max(x: float16, y: float16) -> float16 {
+  return x.max(y);
+}
+
+#[inline(never)]
+fn min(x: float16, y: float16) -> float16 {
+  return x.min(y);
+}
+
+#[inline(never)]
+fn reduce_add(x: float16) -> f32 {
+  return x.reduce_add();
+}
+
+#[inline(never)]
+fn reduce_max(x: float16) -> f32 {
+  return x.reduce_max();
+}
+
+#[inline(never)]
+fn reduce_min(x: float16) -> f32 {
+  return x.reduce_min();
+}
+
+#[test]
+fn test() {
+  let a = float16(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+  let b = float16(2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0);
+  let c = float16(-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0, -9.0, -10.0, -11.0, -12.0, -13.0, -14.0, -15.0, -16.0);
+  let z = float16::broadcast(0.0);
+
+  assert_eq!(abs(a), a);
+  assert_eq!(abs(b), b);
+  assert_eq!(abs(c), a);
+
+  assert_eq!(dot(a, b), 1632.0);
+  assert_eq!(dot(b, a), 1632.0);
+
+  assert_eq!(add(a, b), float16(3.0, 5.0, 7.0, 9.0, 11.0, 13.0, 15.0, 17.0, 19.0, 21.0, 23.0, 25.0, 27.0, 29.0, 31.0, 33.0));
+  assert_eq!(add(b, a), float16(3.0, 5.0, 7.0, 9.0, 11.0, 13.0, 15.0, 17.0, 19.0, 21.0, 23.0, 25.0, 27.0, 29.0, 31.0, 33.0));
+
+  assert_eq!(sub(a, b), float16(-1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0));
+  assert_eq!(sub(b, a), float16(1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0));
+
+  assert_eq!(mul(a, b), float16(2.0, 6.0, 12.0, 20.0, 30.0, 42.0, 56.0, 72.0, 90.0, 110.0, 132.0, 156.0, 182.0, 210.0, 240.0, 272.0));
+  assert_eq!(mul(b, a), float16(2.0, 6.0, 12.0, 20.0, 30.0, 42.0, 56.0, 72.0, 90.0, 110.0, 132.0, 156.0, 182.0, 210.0, 240.0, 272.0));
+
+  assert_eq!(div(a, b), a / b);
+  assert_eq!(div(b, a), b / a);
+
+  assert_eq!(sqrt(a * a), a);
+  assert_eq!(sqrt(b * b), b);
+
+  assert_near_f32!(rsqrt(a), 1.0 / a.sqrt(), 1);
+  assert_near_f32!(rsqrt(b), 1.0 / b.sqrt(), 1);
+  assert_near_f32!(rsqrt(z), 1.0 / z.sqrt(), 0);
+
+  assert_near_f32!(recip_fast(a), 1.0 / a, 4996);
+  assert_near_f32!(recip_fast(c), 1.0 / c, 4996);
+  assert_near_f32!(recip_nr1(a), 1.0 / a, 3);
+  assert_near_f32!(recip_nr1(c), 1.0 / c, 3);
+  assert_near_f32!(recip_nr1(z), 1.0 / z, 0);
+
+  assert_near_f32!(rsqrt_fast(a), 1.0 / a.sqrt(), 4980);
+  assert_near_f32!(rsqrt_nr1(a), 1.0 / a.sqrt(), 5);
+  assert_near_f32!(rsqrt_nr1(b), 1.0 / b.sqrt(), 5);
+  assert_near_f32!(rsqrt_nr1(z), 1.0 / z.sqrt(), 0);
+
+  assert_eq!(add_mul(a, b, c), float16(-1.0, -4.0, -9.0, -16.0, -25.0, -36.0, -49.0, -64.0, -81.0, -100.0, -121.0, -144.0, -169.0, -196.0, -225.0, -256.0));
+  assert_eq!(add_mul(b, a, c), float16(1.0, -1.0, -5.0, -11.0, -19.0, -29.0, -41.0, -55.0, -71.0, -89.0, -109.0, -131.0, -155.0, -181.0, -209.0, -239.0));
+
+  assert_eq!(max(a, b), b);
+  assert_eq!(max(b, a), b);
+  assert_eq!(max(a, c), a);
+
+  assert_eq!(min(a, b), a);
+  assert_eq!(min(b, a), a);
+  assert_eq!(min(a, c), c);
+
+  assert_eq!(max(a, float16::broadcast(std::f32::NAN)), a);
+  assert_eq!(min(float16::broadcast(std::f32::NAN), a), a);
+
+  assert_eq!(reduce_add(a), 136.0);
+  assert_eq!(reduce_add(c), -136.0);
+
+  assert_eq!(reduce_max(a), 16.0);
+  assert_eq!(reduce_max(c), -1.0);
+
+  assert_eq!(reduce_min(a), 1.0);
+  assert_eq!(reduce_min(c), -16.0);
+}
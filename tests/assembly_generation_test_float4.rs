@@ -39,6 +39,26 @@ fn rsqrt(x: float4) -> float4 {
   return x.rsqrt();
 }
 
+#[inline(never)]
+fn recip_fast(x: float4) -> float4 {
+  return x.recip_fast();
+}
+
+#[inline(never)]
+fn recip_nr1(x: float4) -> float4 {
+  return x.recip_nr1();
+}
+
+#[inline(never)]
+fn rsqrt_fast(x: float4) -> float4 {
+  return x.rsqrt_fast();
+}
+
+#[inline(never)]
+fn rsqrt_nr1(x: float4) -> float4 {
+  return x.rsqrt_nr1();
+}
+
 #[inline(never)]
 fn add_mul(x: float4, y: float4, z: float4) -> float4 {
   return x.add_mul(y, z);
@@ -94,6 +114,17 @@ fn test() {
   assert_near_f32!(rsqrt(b), 1.0 / b.sqrt(), 1);
   assert_near_f32!(rsqrt(z), 1.0 / z.sqrt(), 0);
 
+  assert_near_f32!(recip_fast(a), 1.0 / a, 4996);
+  assert_near_f32!(recip_fast(c), 1.0 / c, 4996);
+  assert_near_f32!(recip_nr1(a), 1.0 / a, 3);
+  assert_near_f32!(recip_nr1(c), 1.0 / c, 3);
+  assert_near_f32!(recip_nr1(z), 1.0 / z, 0);
+
+  assert_near_f32!(rsqrt_fast(a), 1.0 / a.sqrt(), 4980);
+  assert_near_f32!(rsqrt_nr1(a), 1.0 / a.sqrt(), 5);
+  assert_near_f32!(rsqrt_nr1(b), 1.0 / b.sqrt(), 5);
+  assert_near_f32!(rsqrt_nr1(z), 1.0 / z.sqrt(), 0);
+
   assert_eq!(add_mul(a, b, c), float4(-1.0, -4.0, -9.0, -16.0));
   assert_eq!(add_mul(b, a, c), float4(1.0, -1.0, -5.0, -11.0));
 
@@ -0,0 +1,101 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+#[inline(never)]
+fn abs(x: int8) -> int8 {
+  return x.abs();
+}
+
+#[inline(never)]
+fn add(x: int8, y: int8) -> int8 {
+  return x + y;
+}
+
+#[inline(never)]
+fn sub(x: int8, y: int8) -> int8 {
+  return x - y;
+}
+
+#[inline(never)]
+fn mul(x: int8, y: int8) -> int8 {
+  return x * y;
+}
+
+#[inline(never)]
+fn add_mul(x: int8, y: int8, z: int8) -> int8 {
+  return x.add_mul(y, z);
+}
+
+#[inline(never)]
+fn dot(x: int8, y: int8) -> i32 {
+  return x.dot(y);
+}
+
+#[inline(never)]
+fn max(x: int8, y: int8) -> int8 {
+  return x.max(y);
+}
+
+#[inline(never)]
+fn min(x: int8, y: int8) -> int8 {
+  return x.min(y);
+}
+
+#[inline(never)]
+fn reduce_add(x: int8) -> i32 {
+  return x.reduce_add();
+}
+
+#[inline(never)]
+fn reduce_max(x: int8) -> i32 {
+  return x.reduce_max();
+}
+
+#[inline(never)]
+fn reduce_min(x: int8) -> i32 {
+  return x.reduce_min();
+}
+
+#[test]
+fn test() {
+  let a = int8(1, 2, 3, 4, 5, 6, 7, 8);
+  let b = int8(2, 3, 4, 5, 6, 7, 8, 9);
+  let c = int8(-1, -2, -3, -4, -5, -6, -7, -8);
+
+  assert_eq!(abs(a), a);
+  assert_eq!(abs(b), b);
+  assert_eq!(abs(c), a);
+
+  assert_eq!(dot(a, b), 240);
+  assert_eq!(dot(b, a), 240);
+
+  assert_eq!(add(a, b), int8(3, 5, 7, 9, 11, 13, 15, 17));
+  assert_eq!(add(b, a), int8(3, 5, 7, 9, 11, 13, 15, 17));
+
+  assert_eq!(sub(a, b), int8(-1, -1, -1, -1, -1, -1, -1, -1));
+  assert_eq!(sub(b, a), int8(1, 1, 1, 1, 1, 1, 1, 1));
+
+  assert_eq!(mul(a, b), int8(2, 6, 12, 20, 30, 42, 56, 72));
+  assert_eq!(mul(b, a), int8(2, 6, 12, 20, 30, 42, 56, 72));
+
+  assert_eq!(add_mul(a, b, c), int8(-1, -4, -9, -16, -25, -36, -49, -64));
+  assert_eq!(add_mul(b, a, c), int8(1, -1, -5, -11, -19, -29, -41, -55));
+
+  assert_eq!(max(a, b), b);
+  assert_eq!(max(b, a), b);
+  assert_eq!(max(a, c), a);
+
+  assert_eq!(min(a, b), a);
+  assert_eq!(min(b, a), a);
+  assert_eq!(min(a, c), c);
+
+  assert_eq!(reduce_add(a), 36);
+  assert_eq!(reduce_add(c), -36);
+
+  assert_eq!(reduce_max(a), 8);
+  assert_eq!(reduce_max(c), -1);
+
+  assert_eq!(reduce_min(a), 1);
+  assert_eq!(reduce_min(c), -8);
+}
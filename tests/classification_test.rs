@@ -0,0 +1,29 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+#[test]
+fn test_classification() {
+  let x = float8(1.0, -0.0, std::f32::NAN, std::f32::INFINITY, -std::f32::INFINITY, 1e-40, -std::f32::MIN_POSITIVE, -std::f32::NAN);
+
+  assert_eq!(is_nan(x).to_bitmask(), 0b10000100);
+  assert_eq!(is_infinite(x).to_bitmask(), 0b00011000);
+  assert_eq!(is_finite(x).to_bitmask(), 0b01100011);
+  assert_eq!(is_normal(x).to_bitmask(), 0b01000001);
+  assert_eq!(is_subnormal(x).to_bitmask(), 0b00100000);
+  assert_eq!(signbit(x).to_bitmask(), 0b11010010);
+
+  let y = double4(std::f64::NAN, std::f64::MAX, 4.9e-324, -0.0);
+
+  assert_eq!(is_nan(y), long4(-1, 0, 0, 0));
+  assert_eq!(is_finite(y), long4(0, -1, -1, -1));
+  assert_eq!(is_normal(y), long4(0, -1, 0, 0));
+  assert_eq!(is_subnormal(y), long4(0, 0, -1, 0));
+  assert_eq!(signbit(y), long4(0, 0, 0, -1));
+}
+
+#[test]
+fn test_nan_to_num() {
+  assert_eq!(nan_to_num(float4(1.0, std::f32::NAN, std::f32::INFINITY, -std::f32::NAN), 0.0), float4(1.0, 0.0, std::f32::INFINITY, 0.0));
+  assert_eq!(nan_to_num(double2(std::f64::NAN, -2.0), -1.0), double2(-1.0, -2.0));
+}
@@ -0,0 +1,79 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+// The dispatch state is global, so every path is exercised from a single test
+#[test]
+fn test_dispatch() {
+  let a = float4(1.0, 2.0, 3.0, 4.0);
+  let b = float4(2.0, 3.0, 4.0, 5.0);
+  let c = float4(-1.0, -2.0, -3.0, -4.0);
+
+  assert_eq!(dispatch::detected() & !dispatch::ALL, 0);
+
+  for &features in [0, dispatch::SSE, dispatch::SSE | dispatch::SSE2, dispatch::SSE41, dispatch::FMA, dispatch::AVX | dispatch::AVX2, dispatch::AVX512F, dispatch::ALL].iter() {
+    dispatch::restrict(features);
+
+    assert_eq!(dispatch::features() & !dispatch::detected(), 0);
+
+    assert_eq!(a.dot(b), 40.0);
+    assert_eq!(b.dot(a), 40.0);
+
+    assert_eq!(a.add_mul(b, c), float4(-1.0, -4.0, -9.0, -16.0));
+    assert_eq!(b.add_mul(a, c), float4(1.0, -1.0, -5.0, -11.0));
+
+    assert_eq!(float4(1.0, 4.0, 9.0, 16.0).sqrt(), a);
+    assert_eq!(float4(1.0, 4.0, 16.0, 64.0).rsqrt_nr1().approx_eq_rel(float4(1.0, 0.5, 0.25, 0.125), 1e-6).all(), true);
+    assert_eq!(float8::broadcast(4.0).sqrt(), float8::broadcast(2.0));
+    assert_eq!(int4(-1, 0, -1, 0).to_bitmask(), 0b0101);
+    assert_eq!(char16::broadcast(0).replace(1, -1).replace(15, -128).to_bitmask(), 0x8002);
+
+    let mut d = [0.0; 3];
+    let mut x = [c, a, b];
+    let mut r = [a * a, b, float4(1.0, 4.0, 16.0, 64.0)];
+
+    float4::dot_slice(&[a, b, c], &[b, a, a], &mut d);
+    assert_eq!(d, [40.0, 40.0, -30.0]);
+
+    float4::add_mul_slice(&mut x, &[a, b, c], &[b, c, a]);
+    assert_eq!(x, [c.add_mul(a, b), a.add_mul(b, c), b.add_mul(c, a)]);
+
+    float4::rsqrt_slice(&mut r);
+    assert_eq!(r, [(a * a).rsqrt(), b.rsqrt(), float4(1.0, 4.0, 16.0, 64.0).rsqrt()]);
+    assert_approx_eq_ulps!(double8(1.0, 4.0, 16.0, 64.0, 0.25, 2.0, 3.0, 1e300).rsqrt(), 1.0 / double8(1.0, 4.0, 16.0, 64.0, 0.25, 2.0, 3.0, 1e300).sqrt(), 3);
+
+    // Every path has to add the lanes in the same order, the fold would lose the small lanes next to 1e8
+    assert_eq!(float8(1e8, 1.0, 1.0, 1.0, -1e8, 1.0, 1.0, 1.0).reduce_add(), 6.0);
+    assert_eq!(double4(1e17, 1.0, -1e17, 1.0).reduce_add(), 2.0);
+    assert_eq!(double8(1e17, 1.0, 1.0, 1.0, -1e17, 1.0, 1.0, 1.0).reduce_add(), 6.0);
+    assert_eq!(float16::broadcast(1.0).replace(0, 1e8).replace(8, -1e8).reduce_add(), 14.0);
+
+    check_estimates::<float4>();
+    check_estimates::<float8>();
+    check_estimates::<float16>();
+  }
+
+  dispatch::reset();
+
+  assert_eq!(dispatch::features(), dispatch::detected());
+}
+
+// Sweeps normal inputs across every exponent and checks the documented error bounds of the estimates
+fn check_estimates<T: Float<FloatScalar=f32> + std::fmt::Debug>() {
+  let (lo, hi) = (0x0100_0000u32, 0x7E00_0000u32);
+  let count = 4096;
+  let stride = (hi - lo) / (count * T::LANES as u32);
+
+  for i in 0..count {
+    let x = (0..T::LANES as u32).map(|j| f32::from_bits(lo + (i * T::LANES as u32 + j) * stride)).collect::<T>();
+    let recip = x.map_unary(&|v| (1.0 / v as f64) as f32);
+    let rsqrt = x.map_unary(&|v| (1.0 / (v as f64).sqrt()) as f32);
+
+    assert_approx_eq_ulps!(x.recip_fast(), recip, 4996);
+    assert_approx_eq_ulps!(x.recip_nr1(), recip, 3);
+    assert_approx_eq_ulps!(x.rsqrt_fast(), rsqrt, 4980);
+    assert_approx_eq_ulps!(x.rsqrt_nr1(), rsqrt, 5);
+    assert_approx_eq_ulps!(x.rsqrt(), rsqrt, 5);
+    assert_approx_eq_ulps!(x.rsqrt_accurate(), rsqrt, 1);
+  }
+}
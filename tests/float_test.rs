@@ -48,6 +48,23 @@ fn test_rsqrt() {
   assert_eq!(rsqrt(double4(100.0, 4.0, 1.0, 9.0)), double4(0.1, 0.5, 1.0, 1.0 / 3.0));
 }
 
+#[test]
+fn test_rsqrt_accurate() {
+  // Every exponent of the normal range with a few mantissas each, against the square root in double precision
+  for i in 0 .. 4096u32 {
+    let x = (0 .. 16).map(|j| f32::from_bits(0x0080_0000 + (i * 16 + j) * 0x7EF0)).collect::<float16>();
+    let expected = x.map_unary(&|v| (1.0 / (v as f64).sqrt()) as f32);
+
+    assert_approx_eq_ulps!(rsqrt_accurate(x), expected, 1);
+    assert_approx_eq_ulps!(rsqrt_accurate(x.lo()), expected.lo(), 1);
+    assert_approx_eq_ulps!(rsqrt_accurate(x.lo().lo()), expected.lo().lo(), 1);
+    assert_approx_eq_ulps!(rsqrt_accurate(x.lo().lo().lo()), expected.lo().lo().lo(), 1);
+  }
+
+  assert_eq!(rsqrt_accurate(float4(0.0, -0.0, std::f32::INFINITY, 0.25)), float4(std::f32::INFINITY, -std::f32::INFINITY, 0.0, 2.0));
+  assert_eq!(rsqrt_accurate(double8(100.0, 4.0, 1.0, 9.0, 0.25, 0.0625, 65536.0, 16.0)), 1.0 / double8(10.0, 2.0, 1.0, 3.0, 0.5, 0.25, 256.0, 4.0));
+}
+
 #[test]
 fn test_fract() {
   assert_eq!(fract(float2(1.5, 2.75)), float2(0.5, 0.75));
@@ -121,4 +138,46 @@ fn test_dot() {
   assert_near_f32_scalar!(dot(float2(10.0, -2.0), float2(0.1, 0.5)), 0.0, 1);
   assert_near_f32_scalar!(dot(float3(10.0, -2.0, 3.0), float3(0.1, 0.5, 1.0)), 3.0, 1);
   assert_near_f32_scalar!(dot(float4(10.0, -2.0, 3.0, 0.0), float4(0.1, 0.5, 1.0, -4.0)), 3.0, 1);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_maximum_minimum() {
+  let nan = std::f32::NAN;
+
+  assert_eq!(maximum(float4(1.0, -2.0, 0.0, -0.0), float4(0.5, 3.0, -0.0, 0.0)).to_boolean(), float4(1.0, 3.0, 0.0, 0.0).to_boolean());
+  assert_eq!(minimum(float4(1.0, -2.0, 0.0, -0.0), float4(0.5, 3.0, -0.0, 0.0)).to_boolean(), float4(0.5, -2.0, -0.0, -0.0).to_boolean());
+
+  assert_eq!(is_nan(maximum(float4(nan, 1.0, nan, 2.0), float4(1.0, nan, nan, 1.0))), int4(-1, -1, -1, 0));
+  assert_eq!(is_nan(minimum(double2(std::f64::NAN, 1.0), double2(1.0, 0.0))), long2(-1, 0));
+}
+
+#[test]
+fn test_maxnum_minnum() {
+  let nan = std::f64::NAN;
+
+  assert_eq!(maxnum(double4(nan, 1.0, -0.0, 2.0), double4(1.0, nan, 0.0, 3.0)).to_boolean(), double4(1.0, 1.0, 0.0, 3.0).to_boolean());
+  assert_eq!(minnum(double4(nan, 1.0, 0.0, 2.0), double4(1.0, nan, -0.0, 3.0)).to_boolean(), double4(1.0, 1.0, -0.0, 2.0).to_boolean());
+  assert!(is_nan(maxnum(float2(std::f32::NAN, 0.0), float2(std::f32::NAN, 0.0))).lo() != 0);
+}
+
+#[test]
+fn test_maxmag_minmag() {
+  assert_eq!(maxmag(float4(-3.0, 1.0, -2.0, 2.0), float4(2.0, -4.0, 2.0, std::f32::NAN)), float4(-3.0, -4.0, 2.0, 2.0));
+  assert_eq!(minmag(float4(-3.0, 1.0, -2.0, 2.0), float4(2.0, -4.0, 2.0, std::f32::NAN)), float4(2.0, 1.0, -2.0, 2.0));
+}
+
+#[test]
+fn test_fdim() {
+  assert_eq!(fdim(float4(3.0, 1.0, -1.0, 2.0), float4(1.0, 3.0, -1.0, -2.0)), float4(2.0, 0.0, 0.0, 4.0));
+  assert!(fdim(double2(std::f64::NAN, 1.0), double2(0.0, 2.0)).0.is_nan());
+}
+
+#[test]
+fn test_fma() {
+  // The rounding error of x * x is lost without fusing
+  let x = float4::broadcast(1.0 + std::f32::EPSILON);
+
+  assert_eq!(fma(x, x, -(x * x)), float4::broadcast(std::f32::EPSILON * std::f32::EPSILON));
+  assert_eq!(fma(double2(0.1, 1e308), double2(10.0, 10.0), double2(-1.0, -std::f64::INFINITY)), double2(0.1f64.mul_add(10.0, -1.0), -std::f64::INFINITY));
+  assert_eq!(fma(float8::broadcast(2.0), float8::broadcast(3.0), float8::broadcast(1.0)), float8::broadcast(7.0));
+}
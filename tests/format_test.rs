@@ -0,0 +1,43 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+#[test]
+fn test_display_vector() {
+  assert_eq!(format!("{}", float4(1.0, -2.5, 3.0, 0.25)), "(1, -2.5, 3, 0.25)");
+  assert_eq!(format!("{:.3}", float2(1.0, 2.0 / 3.0)), "(1.000, 0.667)");
+  assert_eq!(format!("{:+4}", int3(1, -2, 30)), "(  +1,   -2,  +30)");
+}
+
+#[test]
+fn test_display_matrix() {
+  let m = float2x3::from_rows(float2(1.0, -20.0), float2(0.5, 3.0), float2(100.0, 0.0));
+
+  assert_eq!(format!("{}", m), "[  1, -20]\n[0.5,   3]\n[100,   0]");
+  assert_eq!(format!("{:.1}", double2x2::identity()), "[1.0, 0.0]\n[0.0, 1.0]");
+}
+
+#[test]
+fn test_parse_vector() {
+  assert_eq!("(1, 2, 3, 4)".parse(), Ok(float4(1.0, 2.0, 3.0, 4.0)));
+  assert_eq!("float4(1, 2.5, -3, 4,)".parse(), Ok(float4(1.0, 2.5, -3.0, 4.0)));
+  assert_eq!(" uchar2 ( 255 ,0 ) ".parse(), Ok(uchar2(255, 0)));
+
+  assert_eq!("int4(1, 2, 3, 4)".parse::<float4>(), Err(ParseError::Expected("float4", 0)));
+  assert_eq!("(1, 2, 3)".parse::<float4>(), Err(ParseError::WrongLength { expected: 4, found: 3, position: 9 }));
+  assert_eq!("(1, 256)".parse::<uchar2>(), Err(ParseError::InvalidNumber(4)));
+  assert_eq!("(1 2)".parse::<int2>(), Err(ParseError::Expected(",", 3)));
+  assert_eq!("(1, 2) x".parse::<int2>(), Err(ParseError::Expected("end of input", 7)));
+}
+
+#[test]
+fn test_parse_matrix() {
+  let m = float3x2::from_rows(float3(1.0, 2.0, 3.0), float3(4.0, 5.0, 6.0));
+
+  assert_eq!(format!("{:.2}", m).parse(), Ok(m));
+  assert_eq!(format!("{:?}", m).parse(), Ok(m));
+  assert_eq!("[1, 2, 3] [4, 5, 6]".parse(), Ok(m));
+
+  assert_eq!("[1, 2, 3]".parse::<float3x2>(), Err(ParseError::WrongLength { expected: 2, found: 1, position: 9 }));
+  assert_eq!("float3x2(float2(1, 4), float3(2, 5, 0))".parse::<float3x2>(), Err(ParseError::Expected("float2", 23)));
+}
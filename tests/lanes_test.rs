@@ -0,0 +1,65 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+fn sum_lanes<T: Vector>(x: T) -> T::Scalar {
+  let mut sum = x[0];
+
+  for i in 1 .. T::LANES {
+    sum = sum + x[i];
+  }
+
+  return sum;
+}
+
+#[test]
+fn test_arrays() {
+  assert_eq!(float3::from([1.0, 2.0, 3.0]), float3(1.0, 2.0, 3.0));
+  assert_eq!(<[i16; 4]>::from(short4(1, -2, 3, -4)), [1, -2, 3, -4]);
+
+  let a: [u8; 16] = uchar16(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15).into();
+
+  assert_eq!(a[15], 15);
+  assert_eq!(float3(1.0, 2.0, 3.0).as_ref(), &[1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_index() {
+  let mut x = int4(1, 2, 3, 4);
+
+  x[2] = 10;
+  x.as_mut()[0] = -1;
+
+  assert_eq!(x, int4(-1, 2, 10, 4));
+  assert_eq!(x[3], 4);
+  assert_eq!(sum_lanes(double3(1.0, 2.0, 3.0)), 6.0);
+  assert_eq!(sum_lanes(uint8(1, 2, 3, 4, 5, 6, 7, 8)), 36);
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_bounds() {
+  let x = float3(1.0, 2.0, 3.0);
+
+  x[3];
+}
+
+#[test]
+fn test_iterators() {
+  assert_eq!(long3(1, 2, 3).into_iter().rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+  assert_eq!(float4(1.0, 2.0, 3.0, 4.0).into_iter().len(), 4);
+  assert_eq!((0 .. 8).map(|x| x as f32).collect::<float8>(), float8(0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0));
+  assert_eq!(int2(3, 4).into_iter().map(|x| x * 2).collect::<int2>(), int2(6, 8));
+}
+
+#[test]
+#[should_panic(expected = "expected 4 elements for float4 but got 3")]
+fn test_collect_too_short() {
+  let _ = vec![1.0, 2.0, 3.0].into_iter().collect::<float4>();
+}
+
+#[test]
+fn test_default() {
+  assert_eq!(char3::default(), char3(0, 0, 0));
+  assert_eq!(double2::default(), double2(0.0, 0.0));
+}
@@ -0,0 +1,37 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+use std::mem::{align_of, size_of};
+
+#[test]
+fn test_layout() {
+  assert_eq!((size_of::<char2>(), align_of::<char2>()), (2, 2));
+  assert_eq!((size_of::<char3>(), align_of::<char3>()), (4, 4));
+  assert_eq!((size_of::<char16>(), align_of::<char16>()), (16, 16));
+
+  assert_eq!((size_of::<ushort3>(), align_of::<ushort3>()), (8, 8));
+
+  assert_eq!((size_of::<int2>(), align_of::<int2>()), (8, 8));
+  assert_eq!((size_of::<int4>(), align_of::<int4>()), (16, 16));
+
+  assert_eq!((size_of::<float3>(), align_of::<float3>()), (16, 16));
+  assert_eq!((size_of::<float4>(), align_of::<float4>()), (16, 16));
+  assert_eq!((size_of::<float8>(), align_of::<float8>()), (32, 32));
+  assert_eq!((size_of::<float16>(), align_of::<float16>()), (64, 64));
+
+  assert_eq!((size_of::<double3>(), align_of::<double3>()), (32, 32));
+  assert_eq!((size_of::<ulong16>(), align_of::<ulong16>()), (128, 128));
+
+  assert_eq!((size_of::<float3x3>(), align_of::<float3x3>()), (48, 16));
+  assert_eq!((size_of::<double4x4>(), align_of::<double4x4>()), (128, 32));
+}
+
+#[test]
+fn test_lanes() {
+  assert_eq!(char2::LANES, 2);
+  assert_eq!(float3::LANES, 3);
+  assert_eq!(double16::LANES, 16);
+
+  assert_eq!(float3(1.0, 2.0, 3.0).extract(2), 3.0);
+  assert_eq!(int3(1, 2, 3).replace(1, 5), int3(1, 5, 3));
+}
@@ -0,0 +1,46 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+#[test]
+fn test_to_bitmask() {
+  assert_eq!(float4(1.0, -2.0, 3.0, -4.0).lt(float4::broadcast(0.0)).to_bitmask(), 0b1010);
+  assert_eq!(double2(1.0, 2.0).eq(double2(1.0, 3.0)).to_bitmask(), 0b01);
+  assert_eq!(int3(1, 2, 3).gt(int3(0, 5, 0)).to_bitmask(), 0b101);
+  assert_eq!(float8(0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0).ge(float8::broadcast(5.0)).to_bitmask(), 0b11100000);
+  assert_eq!(double4(0.0, 1.0, 2.0, 3.0).ne(double4::broadcast(2.0)).to_bitmask(), 0b1011);
+
+  let x = char16(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+  assert_eq!((x & 1).eq(char16::broadcast(0)).to_bitmask(), 0x5555);
+  assert_eq!(to_bitmask(short8::broadcast(-1)), 0xFF);
+}
+
+#[test]
+fn test_from_bitmask() {
+  assert_eq!(int4::from_bitmask(0b0110), int4(0, -1, -1, 0));
+  assert_eq!(long3::from_bitmask(0xFFFF), long3(-1, -1, -1));
+
+  for bits in 0 .. 256 {
+    assert_eq!(int8::from_bitmask(bits).to_bitmask(), bits);
+  }
+}
+
+#[test]
+fn test_lanes() {
+  let visible = float4(-1.0, 2.0, 0.5, 3.0).gt(float4::broadcast(1.0));
+
+  assert_eq!(visible.count_ones(), 2);
+  assert_eq!(visible.first_set(), Some(1));
+  assert_eq!(visible.iter_set_lanes().collect::<Vec<_>>(), vec![1, 3]);
+
+  assert_eq!(int4::from(0).first_set(), None);
+  assert_eq!(int4::from(0).iter_set_lanes().next(), None);
+}
+
+#[test]
+fn test_select() {
+  let mask = int4::from_bitmask(0b0011);
+
+  assert_eq!(mask.select(float4(1.0, 2.0, 3.0, 4.0), float4(5.0, 6.0, 7.0, 8.0)), float4(5.0, 6.0, 3.0, 4.0));
+}
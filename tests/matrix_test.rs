@@ -30,4 +30,239 @@ fn look_at() {
   assert_near_f32!(look_at.1, translation.1, 1);
   assert_near_f32!(look_at.2, translation.2, 1);
   assert_near_f32!(look_at.3, translation.3, 1);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_inverse() {
+  assert_eq!(float2x2::from_rows(float2(2.0, 1.0), float2(1.0, 1.0)).inverse(), float2x2::from_rows(float2(1.0, -1.0), float2(-1.0, 2.0)));
+  assert_eq!(double3x3::from_rows(double3(1.0, 2.0, 3.0), double3(0.0, 1.0, 4.0), double3(5.0, 6.0, 0.0)).inverse(), double3x3::from_rows(double3(-24.0, 18.0, 5.0), double3(20.0, -15.0, -4.0), double3(-5.0, 4.0, 1.0)));
+
+  let a = float4x4::from_rows(float4(1.0, 2.0, 0.0, 0.0), float4(0.0, 1.0, 3.0, 0.0), float4(0.0, 0.0, 1.0, 4.0), float4(0.0, 0.0, 0.0, 1.0));
+
+  assert_eq!(a.inverse(), float4x4::from_rows(float4(1.0, -2.0, 6.0, -24.0), float4(0.0, 1.0, -3.0, 12.0), float4(0.0, 0.0, 1.0, -4.0), float4(0.0, 0.0, 0.0, 1.0)));
+}
+
+#[test]
+fn test_diagonal() {
+  let a = float3x3::from_diagonal(float3(1.0, 2.0, 3.0));
+
+  assert_eq!(a, float3x3::from_rows(float3(1.0, 0.0, 0.0), float3(0.0, 2.0, 0.0), float3(0.0, 0.0, 3.0)));
+  assert_eq!(a.diagonal(), float3(1.0, 2.0, 3.0));
+  assert_eq!(a.trace(), 6.0);
+
+  assert_eq!(double2x2(double2(1.0, 2.0), double2(3.0, 4.0)).diagonal(), double2(1.0, 4.0));
+  assert_eq!(double4x4::identity().trace(), 4.0);
+
+  assert_eq!(float4x4::from_scale_vector(float3(2.0, 3.0, 4.0)) * float4(1.0, 1.0, 1.0, 1.0), float4(2.0, 3.0, 4.0, 1.0));
+  assert_eq!(float4x4::from_scale_vector(float3::broadcast(2.0)), float4x4::from_scale(2.0));
+}
+
+#[test]
+fn test_determinant() {
+  assert_eq!(determinant(float2x2::from_rows(float2(1.0, 2.0), float2(3.0, 4.0))), -2.0);
+  assert_eq!(determinant(double3x3::from_rows(double3(2.0, 0.0, 1.0), double3(1.0, 3.0, 2.0), double3(1.0, 1.0, 2.0))), 6.0);
+  assert_eq!(float3x3::from_diagonal(float3(1.0, -1.0, 1.0)).determinant(), -1.0);
+
+  let a = double4x4::from_rows(double4(1.0, 0.0, 2.0, -1.0), double4(3.0, 0.0, 0.0, 5.0), double4(2.0, 1.0, 4.0, -3.0), double4(1.0, 0.0, 5.0, 0.0));
+
+  assert_eq!(a.determinant(), 30.0);
+  assert_eq!(a.transpose().determinant(), 30.0);
+  assert_eq!(float4x4::from_scale_vector(float3(2.0, 3.0, 4.0)).determinant(), 24.0);
+}
+
+#[test]
+fn test_try_inverse() {
+  let a = double3x3::from_rows(double3(1.0, 2.0, 3.0), double3(0.0, 1.0, 4.0), double3(5.0, 6.0, 0.0));
+
+  assert_eq!(a.inverse_with_det(), (a.inverse(), 1.0));
+  assert_eq!(a.try_inverse(), Some(a.inverse()));
+  assert_eq!(a.try_inverse_epsilon(1.0), None);
+
+  assert_eq!(float4x4::from_scale(0.0).try_inverse(), None);
+  assert_eq!(float3x3::from_rows(float3(1.0, 2.0, 3.0), float3(2.0, 4.0, 6.0), float3(0.0, 1.0, 1.0)).try_inverse(), None);
+  assert_eq!(float2x2::from_diagonal(float2(1e-30, 1e-30)).try_inverse(), None);
+  assert!(float2x2::from_diagonal(float2(std::f32::NAN, 1.0)).try_inverse().is_none());
+
+  assert_eq!(float4x4::identity().condition_number_estimate(), 1.0);
+  assert_eq!(double2x2::from_diagonal(double2(1.0, 1e-3)).condition_number_estimate(), 1e3);
+  assert_eq!(float3x3::from_diagonal(float3::broadcast(0.0)).condition_number_estimate(), std::f32::INFINITY);
+}
+
+#[test]
+fn test_affine_inverse() {
+  let rigid = float4x4::from_translation(1.0, -2.0, 3.0) * float4x4::from_euler_angles(0.3, -0.5, 1.2);
+  let affine = rigid * float4x4::from_scale_vector(float3(2.0, 0.5, 4.0));
+
+  assert!(rigid.inverse_rigid().almost_equal_elements(rigid.inverse(), 1e-5));
+  assert!(affine.inverse_affine().almost_equal_elements(affine.inverse(), 1e-5));
+  assert!((affine * affine.inverse_affine()).almost_equal_elements(float4x4::identity(), 1e-5));
+
+  let normal = affine.normal_matrix();
+  let n = float3x3(float3(2.0, 0.0, 0.0), float3(0.0, 0.5, 0.0), float3(0.0, 0.0, 4.0)).inverse();
+
+  assert!(float4x4::from_scale_vector(float3(2.0, 0.5, 4.0)).normal_matrix().almost_equal_elements(n, 1e-6));
+  assert!((normal.transpose() * float3x3(float3((affine.0).0, (affine.0).1, (affine.0).2), float3((affine.1).0, (affine.1).1, (affine.1).2), float3((affine.2).0, (affine.2).1, (affine.2).2))).almost_equal_elements(float3x3::identity(), 1e-5));
+
+  let a = double4x3(double3(0.0, 2.0, 0.0), double3(-2.0, 0.0, 0.0), double3(0.0, 0.0, 2.0), double3(1.0, 2.0, 3.0));
+  let b = a.inverse_affine();
+
+  assert_eq!(b, double4x3(double3(0.0, -0.5, 0.0), double3(0.5, 0.0, 0.0), double3(0.0, 0.0, 0.5), double3(-1.0, 0.5, -1.5)));
+  assert_eq!(double4x3(a.0 * 0.5, a.1 * 0.5, a.2 * 0.5, a.3).inverse_rigid(), double4x3(double3(0.0, -1.0, 0.0), double3(1.0, 0.0, 0.0), double3(0.0, 0.0, 1.0), double3(-2.0, 1.0, -3.0)));
+}
+
+#[test]
+fn test_affine_inverse_rows() {
+  let rigid = float4x4::from_translation(1.0, -2.0, 3.0) * float4x4::from_euler_angles(0.3, -0.5, 1.2);
+  let affine = rigid * float4x4::from_scale_vector(float3(2.0, 0.5, 4.0));
+
+  assert!(rigid.to_affine_rows().inverse_rigid().almost_equal_elements(rigid.inverse().to_affine_rows(), 1e-5));
+  assert!(affine.to_affine_rows().inverse_affine().almost_equal_elements(affine.inverse().to_affine_rows(), 1e-5));
+  assert!(affine.to_affine_rows().normal_matrix().almost_equal_elements(affine.normal_matrix(), 1e-5));
+
+  let a = double3x4(double4(0.0, -2.0, 0.0, 1.0), double4(2.0, 0.0, 0.0, 2.0), double4(0.0, 0.0, 2.0, 3.0));
+
+  assert_eq!(a.inverse_affine(), double3x4(double4(0.0, 0.5, 0.0, -1.0), double4(-0.5, 0.0, 0.0, 0.5), double4(0.0, 0.0, 0.5, -1.5)));
+  assert_eq!(double3x4(a.0 * 0.5, a.1 * 0.5, a.2 * 0.5).inverse_rigid(), double3x4(double4(0.0, 1.0, 0.0, -1.0), double4(-1.0, 0.0, 0.0, 0.5), double4(0.0, 0.0, 1.0, -1.5)));
+}
+
+#[test]
+fn test_products() {
+  let a = float4x3::from_rows(float4(1.0, 2.0, 3.0, 4.0), float4(0.0, 1.0, 0.0, 1.0), float4(2.0, 0.0, 1.0, 0.0));
+  let b = float3x4::from_rows(float3(1.0, 0.0, 2.0), float3(0.0, 1.0, 0.0), float3(1.0, 1.0, 1.0), float3(0.0, 2.0, 0.0));
+  let c = float2x4::from_rows(float2(1.0, 0.0), float2(0.0, 1.0), float2(1.0, 1.0), float2(2.0, 0.0));
+
+  assert_eq!(a * b, float3x3::from_rows(float3(4.0, 13.0, 5.0), float3(0.0, 3.0, 0.0), float3(3.0, 1.0, 5.0)));
+  assert_eq!(a * c, float2x3::from_rows(float2(12.0, 5.0), float2(2.0, 1.0), float2(3.0, 1.0)));
+  assert_eq!(a.dot(c), a * c);
+  assert_eq!((a * b) * float2x3::from_columns(float3(1.0, 0.0, 2.0), float3(0.0, 1.0, -1.0)), a * (b * float2x3::from_columns(float3(1.0, 0.0, 2.0), float3(0.0, 1.0, -1.0))));
+
+  assert_eq!(float3(1.0, 1.0, 2.0) * a, float4(5.0, 3.0, 5.0, 5.0));
+  assert_eq!(float3(1.0, 1.0, 2.0) * a, a.transpose() * float3(1.0, 1.0, 2.0));
+  assert_eq!(double2(1.0, -1.0) * double2x2::identity(), double2(1.0, -1.0));
+
+  assert_eq!(-a, a * -1.0);
+  assert_eq!(a / 2.0, a * 0.5);
+  assert_eq!(a.hadamard(a), float4x3::from_rows(float4(1.0, 4.0, 9.0, 16.0), float4(0.0, 1.0, 0.0, 1.0), float4(4.0, 0.0, 1.0, 0.0)));
+
+  let mut d = double3x3::identity();
+
+  d /= 4.0;
+
+  assert_eq!(d, double3x3::from_diagonal(double3::broadcast(0.25)));
+}
+
+fn sum_of_columns<T: Matrix>(x: T) -> T::Column {
+  let mut sum = T::Column::default();
+
+  for i in 0 .. T::COLUMNS {
+    sum = sum + x.column(i);
+  }
+
+  return sum;
+}
+
+fn power<T: SquareMatrix>(x: T, n: usize) -> T {
+  return (0 .. n).fold(T::identity(), |a, _| a * x);
+}
+
+#[test]
+fn test_matrix_trait() {
+  let a = float3x2::from_rows(float3(1.0, 2.0, 3.0), float3(4.0, 5.0, 6.0));
+
+  assert_eq!(<float3x2 as Matrix>::ROWS, 2);
+  assert_eq!(<float3x2 as Matrix>::COLUMNS, 3);
+  assert_eq!(a.column(1), float2(2.0, 5.0));
+  assert_eq!(Matrix::row(a, 1), float3(4.0, 5.0, 6.0));
+  assert_eq!(sum_of_columns(a), float2(6.0, 15.0));
+  assert_eq!(a.map(&|x| x * 2.0), a * 2.0);
+  assert_eq!(transpose(transpose(a)), a);
+
+  let b = double2x2::from_rows(double2(1.0, 1.0), double2(0.0, 1.0));
+
+  assert_eq!(power(b, 3), double2x2::from_rows(double2(1.0, 3.0), double2(0.0, 1.0)));
+  assert_eq!(inverse(b), double2x2::from_rows(double2(1.0, -1.0), double2(0.0, 1.0)));
+  assert_eq!(trace(b), 2.0);
+  assert_eq!(<double2x2 as SquareMatrix>::identity(), double2x2::identity());
+}
+
+#[test]
+#[should_panic]
+fn test_column_out_of_range() {
+  Matrix::column(float2x4::default(), 2);
+}
+
+#[test]
+fn test_accessors() {
+  let mut a = float3x2::from_rows(float3(1.0, 2.0, 3.0), float3(4.0, 5.0, 6.0));
+
+  assert_eq!(a[2], float2(3.0, 6.0));
+  assert_eq!(a[(1, 0)], 4.0);
+
+  a[(1, 0)] = 7.0;
+  a[2] = float2(8.0, 9.0);
+
+  assert_eq!(a, float3x2::from_rows(float3(1.0, 2.0, 8.0), float3(7.0, 5.0, 9.0)));
+
+  a.set_row(0, float3(0.0, 0.0, 0.0));
+  a.set_column(1, float2(1.0, 1.0));
+
+  assert_eq!(a, float3x2::from_rows(float3(0.0, 1.0, 0.0), float3(7.0, 1.0, 9.0)));
+
+  a.swap_columns(0, 2);
+  a.swap_rows(0, 1);
+
+  assert_eq!(a, float3x2::from_rows(float3(9.0, 1.0, 7.0), float3(0.0, 1.0, 0.0)));
+
+  let b = double2x3::from_row_major([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+  assert_eq!(b, double2x3::from_rows(double2(1.0, 2.0), double2(3.0, 4.0), double2(5.0, 6.0)));
+  assert_eq!(b.to_row_major(), [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+  assert_eq!(<[[f64; 3]; 2]>::from(b), [[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+  assert_eq!(double2x3::from([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]), b);
+}
+
+#[test]
+fn test_submatrices() {
+  let a = float4x4::from_rows(float4(1.0, 2.0, 3.0, 4.0), float4(5.0, 6.0, 7.0, 8.0), float4(9.0, 10.0, 11.0, 12.0), float4(0.0, 0.0, 0.0, 1.0));
+  let r = float3x3::from_rows(float3(1.0, 2.0, 3.0), float3(5.0, 6.0, 7.0), float3(9.0, 10.0, 11.0));
+
+  assert_eq!(a.upper_left_3x3(), r);
+  assert_eq!(a.upper_left_2x2(), float2x2::from_rows(float2(1.0, 2.0), float2(5.0, 6.0)));
+  assert_eq!(r.to_homogeneous(), float4x4::from_rows(float4(1.0, 2.0, 3.0, 0.0), float4(5.0, 6.0, 7.0, 0.0), float4(9.0, 10.0, 11.0, 0.0), float4(0.0, 0.0, 0.0, 1.0)));
+  assert_eq!(a.to_affine().to_homogeneous(), a);
+  assert_eq!(a.to_affine(), float4x3::from_rows(float4(1.0, 2.0, 3.0, 4.0), float4(5.0, 6.0, 7.0, 8.0), float4(9.0, 10.0, 11.0, 12.0)));
+  assert_eq!(a.to_affine_rows(), float3x4(float4(1.0, 2.0, 3.0, 4.0), float4(5.0, 6.0, 7.0, 8.0), float4(9.0, 10.0, 11.0, 12.0)));
+  assert_eq!(float4x4::from_affine_rows(a.to_affine_rows()), a);
+  assert_eq!(double4x4::from_affine_rows(double4x4::identity().to_affine_rows()), double4x4::identity());
+
+  assert_eq!(a.minor(1, 2), float3x3::from_rows(float3(1.0, 2.0, 4.0), float3(9.0, 10.0, 12.0), float3(0.0, 0.0, 1.0)));
+  assert_eq!(double3x3::identity().minor(0, 0), double2x2::identity());
+
+  assert_eq!(a.resize::<float3x3>(), r);
+  assert_eq!(r.resize::<float4x4>(), r.to_homogeneous());
+  assert_eq!(double2x2::from_rows(double2(1.0, 2.0), double2(3.0, 4.0)).resize::<double3x2>(), double3x2::from_rows(double3(1.0, 2.0, 0.0), double3(3.0, 4.0, 0.0)));
+  assert_eq!(double2x2::from_rows(double2(1.0, 2.0), double2(3.0, 4.0)).resize::<double2x4>(), double2x4::from_rows(double2(1.0, 2.0), double2(3.0, 4.0), double2(0.0, 0.0), double2(0.0, 0.0)));
+}
+
+#[test]
+#[should_panic]
+fn test_minor_out_of_range() {
+  float3x3::identity().minor(3, 0);
+}
+
+#[test]
+fn test_precision_conversion() {
+  let a = double4x4::from_translation(1e9 + 0.25, -2.5, 3.0);
+  let b = a.to_float();
+
+  assert_eq!(b, float4x4::from_translation(1e9, -2.5, 3.0));
+  assert_eq!(b.to_double(), double4x4::from_translation(1e9, -2.5, 3.0));
+  assert_eq!(float4x4::from(a), b);
+  assert_eq!(double4x4::from(b), b.to_double());
+
+  let c = float3x2::from_rows(float3(0.5, 1.0, 2.0), float3(-1.0, 0.0, 4.0));
+
+  assert_eq!(c.to_double(), double3x2::from_rows(double3(0.5, 1.0, 2.0), double3(-1.0, 0.0, 4.0)));
+  assert_eq!(c.to_float(), c);
+  assert_eq!(double3x2::from(c).to_float(), c);
+}
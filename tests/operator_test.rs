@@ -0,0 +1,59 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+#[test]
+fn test_vector_assign() {
+  let mut x = float4(1.0, 2.0, 3.0, 4.0);
+
+  x += float4::broadcast(1.0);
+  x *= 2.0;
+  x -= float4(1.0, 1.0, 1.0, 1.0);
+  x /= 0.5;
+
+  assert_eq!(x, float4(6.0, 10.0, 14.0, 18.0));
+
+  let mut y = uint3(0xF0, 0x0F, 7);
+
+  y &= 0x3C;
+  y |= uint3(1, 0, 0);
+  y ^= 1;
+  y <<= 2;
+  y >>= uint3(1, 2, 3);
+  y %= uint3(7, 5, 3);
+
+  assert_eq!(y, uint3(5, 3, 2));
+}
+
+#[test]
+fn test_matrix_assign() {
+  let mut m = float2x2::identity();
+
+  m += float2x2::identity();
+  m *= float2x2::from_rows(float2(1.0, 2.0), float2(3.0, 4.0));
+  m -= float2x2::identity();
+  m *= 0.5;
+
+  assert_eq!(m, float2x2::from_rows(float2(0.5, 2.0), float2(3.0, 3.5)));
+
+  let mut n = double3x2::from_rows(double3(1.0, 2.0, 3.0), double3(4.0, 5.0, 6.0));
+
+  n *= 2.0;
+
+  assert_eq!(n, double3x2::from_rows(double3(2.0, 4.0, 6.0), double3(8.0, 10.0, 12.0)));
+}
+
+#[test]
+fn test_sum_product() {
+  let xs = vec![float4(1.0, 2.0, 3.0, 4.0), float4(2.0, 2.0, 2.0, 2.0), float4(0.5, 0.0, -1.0, 1.0)];
+
+  assert_eq!(xs.iter().sum::<float4>(), float4(3.5, 4.0, 4.0, 7.0));
+  assert_eq!(xs.into_iter().product::<float4>(), float4(1.0, 0.0, -6.0, 8.0));
+  assert_eq!(Vec::<int2>::new().into_iter().product::<int2>(), int2(1, 1));
+
+  let ms = vec![float2x2::identity(), float2x2::from_rows(float2(1.0, 2.0), float2(3.0, 4.0))];
+
+  assert_eq!(ms.iter().sum::<float2x2>(), float2x2::from_rows(float2(2.0, 2.0), float2(3.0, 5.0)));
+  assert_eq!(ms.iter().product::<float2x2>(), ms[1]);
+  assert_eq!(Vec::<float3x2>::new().into_iter().sum::<float3x2>(), float3x2::from_rows(float3(0.0, 0.0, 0.0), float3(0.0, 0.0, 0.0)));
+}
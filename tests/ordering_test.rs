@@ -0,0 +1,47 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+#[test]
+fn test_integer_keys() {
+  let mut voxels = HashMap::new();
+
+  voxels.insert(int3(1, 2, 3), "a");
+  voxels.insert(int3(-1, 0, 7), "b");
+  voxels.insert(int3(1, 2, 3), "c");
+
+  assert_eq!(voxels.len(), 2);
+  assert_eq!(voxels[&int3(1, 2, 3)], "c");
+}
+
+#[test]
+fn test_integer_order() {
+  assert!(uint2(1, 9) < uint2(2, 0));
+  assert!(uint2(1, 9) > uint2(1, 8));
+  assert_eq!(std::cmp::Ord::cmp(&short4(1, 2, 3, 4), &short4(1, 2, 3, 4)), std::cmp::Ordering::Equal);
+
+  let sorted: Vec<char2> = vec![char2(3, -1), char2(-5, 4), char2(3, -2)].into_iter().collect::<BTreeSet<_>>().into_iter().collect();
+
+  assert_eq!(sorted, vec![char2(-5, 4), char2(3, -2), char2(3, -1)]);
+
+  // The lane wise versions still win over the ones from `Ord`
+  assert_eq!(int2(1, 5).max(int2(4, 2)), int2(4, 5));
+  assert_eq!(int2(1, 5).clamp(int2(2, 2), int2(3, 3)), int2(2, 3));
+}
+
+#[test]
+fn test_total_order() {
+  let nan = std::f32::NAN;
+
+  assert_eq!(TotalOrder(float2(0.0, nan)), TotalOrder(float2(-0.0, -nan)));
+  assert!(TotalOrder(float2(1.0, std::f32::INFINITY)) < TotalOrder(float2(1.0, nan)));
+  assert!(TotalOrder(double2(-2.0, 0.0)) < TotalOrder(double2(-1.0, -5.0)));
+
+  let mut set = HashSet::new();
+
+  set.insert(TotalOrder(float3(0.0, nan, 1.0)));
+  set.insert(TotalOrder(float3(-0.0, nan, 1.0)));
+
+  assert_eq!(set.len(), 1);
+}
@@ -0,0 +1,60 @@
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+#[test]
+fn test_reduce() {
+  assert_eq!(reduce_mul(float4(1.0, 2.0, 3.0, 4.0)), 24.0);
+  assert_eq!(reduce_mul(int3(2, -3, 4)), -24);
+  assert_eq!(reduce_add(ushort16::broadcast(3)), 48);
+  assert_eq!(reduce_max(char3(-1, 7, 3)), 7);
+  assert_eq!(reduce_min(double8(4.0, 2.0, 8.0, -1.0, 3.0, 0.0, 9.0, 5.0)), -1.0);
+
+  // Pairwise: (1e8 - 1e8) + (1 + 1) instead of ((1e8 + 1) - 1e8) + 1, which `reduce_add` still does
+  assert_eq!(float4(1e8, 1.0, -1e8, 1.0).reduce_tree(&|a, b| a + b), 2.0);
+  assert_eq!(float4(1e8, 1.0, -1e8, 1.0).reduce_add(), 1.0);
+}
+
+#[test]
+fn test_shuffle() {
+  let x = int8(0, 1, 2, 3, 4, 5, 6, 7);
+
+  assert_eq!(x.swap_lanes(4), int8(4, 5, 6, 7, 0, 1, 2, 3));
+  assert_eq!(x.swap_lanes(1), int8(1, 0, 3, 2, 5, 4, 7, 6));
+  assert_eq!(x.swap_lanes(3), int8(3, 2, 1, 0, 7, 6, 5, 4));
+  assert_eq!(float3(1.0, 2.0, 3.0).swap_lanes(2), float3(3.0, 2.0, 1.0));
+
+  assert_eq!(x.shift_lanes_up(2, int8::broadcast(-1)), int8(-1, -1, 0, 1, 2, 3, 4, 5));
+  assert_eq!(x.shift_lanes_up(3, int8::broadcast(-1)), int8(-1, -1, -1, 0, 1, 2, 3, 4));
+  assert_eq!(x.shift_lanes_up(8, int8::broadcast(-1)), int8::broadcast(-1));
+  assert_eq!(ushort16::broadcast(1).shift_lanes_up(8, ushort16::from(0)).reduce_add(), 8);
+
+  assert_eq!(float3(1e8, 1.0, -1e8).reduce_tree(&|a, b| a + b), 1.0);
+  assert_eq!(float8(1e8, 1.0, 1.0, 1.0, -1e8, 1.0, 1.0, 1.0).reduce_tree(&|a, b| a + b), 6.0);
+}
+
+#[test]
+fn test_scan() {
+  assert_eq!(scan_add(int4(1, 2, 3, 4)), int4(1, 3, 6, 10));
+  assert_eq!(scan_add_exclusive(int4(1, 2, 3, 4)), int4(0, 1, 3, 6));
+  assert_eq!(scan_add(float3(1.0, 2.0, 3.0)), float3(1.0, 3.0, 6.0));
+  assert_eq!(scan_add(uchar8(1, 1, 1, 1, 1, 1, 1, 1)), uchar8(1, 2, 3, 4, 5, 6, 7, 8));
+  assert_eq!(scan_max(short8(3, 1, 4, 1, 5, 9, 2, 6)), short8(3, 3, 4, 4, 5, 9, 9, 9));
+  assert_eq!(scan_max(double2(-2.0, -3.0)), double2(-2.0, -2.0));
+
+  let x = uint16(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+  let y = scan_add(x);
+
+  for i in 0 .. 16 {
+    assert_eq!(y[i], ((i + 1) * (i + 2) / 2) as u32);
+  }
+}
+
+#[test]
+fn test_arg() {
+  assert_eq!(argmin(float4(3.0, -1.0, 2.0, -1.0)), 1);
+  assert_eq!(argmax(float4(3.0, -1.0, 5.0, 5.0)), 2);
+  assert_eq!(argmin(float4(std::f32::NAN, 2.0, 1.0, std::f32::NAN)), 2);
+  assert_eq!(argmax(int3(7, 7, 7)), 0);
+  assert_eq!(argmax(ulong8(1, 2, 3, 4, 5, 6, 7, 100)), 7);
+}
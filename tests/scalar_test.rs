@@ -0,0 +1,64 @@
+#[macro_use] extern crate quickcheck;
+
+extern crate hagane_simd;
+
+// The software math behind `FloatScalar` in `no_std` builds, called directly since the default features use `std`
+use hagane_simd::math;
+
+#[test]
+fn test_rounding() {
+  for &x in [0.0, -0.0, 0.5, -0.5, 1.0, -1.5, 2.5, 1e-310, -1e-310, 4503599627370497.0, -1e300, std::f64::INFINITY].iter() {
+    assert_eq!(math::trunc(x).to_bits(), x.trunc().to_bits());
+    assert_eq!(math::floor(x).to_bits(), x.floor().to_bits());
+    assert_eq!(math::ceil(x).to_bits(), x.ceil().to_bits());
+    assert_eq!((math::trunc(x as f32 as f64) as f32).to_bits(), (x as f32).trunc().to_bits());
+    assert_eq!(math::sqrt(x.abs()), x.abs().sqrt());
+  }
+
+  assert!(math::floor(std::f64::NAN).is_nan());
+}
+
+#[test]
+fn test_trigonometry() {
+  assert_eq!(math::sin(0.0), 0.0);
+  assert_eq!(math::cos(0.0), 1.0);
+
+  assert!(math::sin(std::f64::INFINITY).is_nan());
+  assert!(math::cos(std::f64::NAN).is_nan());
+}
+
+quickcheck! {
+  fn sqrt_is_correctly_rounded(x: f64, y: f32) -> bool {
+    let (x, y) = (x.abs(), y.abs());
+
+    return math::sqrt(x) == x.sqrt() && math::sqrt(y as f64) as f32 == y.sqrt();
+  }
+
+  fn sin_cos_match_std(x: f64) -> bool {
+    let x = x % 1000.0;
+    let (s, c) = math::sin_cos(x);
+
+    return (s - x.sin()).abs() <= 4.0 * std::f64::EPSILON && (c - x.cos()).abs() <= 4.0 * std::f64::EPSILON &&
+      (math::sin(x) - s).abs() <= std::f64::EPSILON && (math::cos(x) - c).abs() <= std::f64::EPSILON &&
+      (math::tan(x) - x.tan()).abs() <= 1e-12 * x.tan().abs().max(1.0);
+  }
+
+  fn fract_matches_std(x: f32) -> bool {
+    return math::fract(x as f64) as f32 == x.fract() || x.is_infinite();
+  }
+}
+
+quickcheck! {
+  fn fma_matches_std(x: f64, y: f64, z: f64, scale: i8) -> bool {
+    let y = y * 2f64.powi(scale as i32 * 8);
+    let f = math::fma(x, y, z);
+
+    return f.to_bits() == x.mul_add(y, z).to_bits() || (f.is_nan() && x.mul_add(y, z).is_nan());
+  }
+
+  fn fma_f32_matches_std(x: f32, y: f32, z: f32) -> bool {
+    let f = math::fma_f32(x, y, z);
+
+    return f.to_bits() == x.mul_add(y, z).to_bits() || (f.is_nan() && x.mul_add(y, z).is_nan());
+  }
+}
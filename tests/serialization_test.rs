@@ -0,0 +1,30 @@
+#![cfg(feature = "serde")]
+
+extern crate hagane_simd;
+extern crate serde_test;
+
+use hagane_simd::*;
+use serde_test::{Token, assert_tokens, assert_de_tokens_error};
+
+#[test]
+fn test_vector() {
+  assert_tokens(&float3(1.0, 2.0, 3.0), &[Token::Tuple { len: 3 }, Token::F32(1.0), Token::F32(2.0), Token::F32(3.0), Token::TupleEnd]);
+  assert_tokens(&int2(-1, 7), &[Token::Tuple { len: 2 }, Token::I32(-1), Token::I32(7), Token::TupleEnd]);
+}
+
+#[test]
+fn test_matrix() {
+  assert_tokens(&float2x2(float2(1.0, 2.0), float2(3.0, 4.0)), &[
+    Token::Tuple { len: 2 },
+    Token::Tuple { len: 2 }, Token::F32(1.0), Token::F32(2.0), Token::TupleEnd,
+    Token::Tuple { len: 2 }, Token::F32(3.0), Token::F32(4.0), Token::TupleEnd,
+    Token::TupleEnd
+  ]);
+}
+
+#[test]
+fn test_errors() {
+  assert_de_tokens_error::<uchar2>(&[Token::Tuple { len: 2 }, Token::I32(1), Token::I32(300)], "invalid value: integer `300`, expected u8");
+  assert_de_tokens_error::<float3>(&[Token::Seq { len: Some(2) }, Token::F32(1.0), Token::F32(2.0), Token::SeqEnd], "invalid length 2, expected float3 as a sequence of 3 elements");
+  assert_de_tokens_error::<int2>(&[Token::Seq { len: Some(3) }, Token::I32(1), Token::I32(2), Token::I32(3), Token::SeqEnd], "invalid length 3, expected int2 as a sequence of 2 elements");
+}
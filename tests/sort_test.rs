@@ -0,0 +1,61 @@
+#[macro_use] extern crate quickcheck;
+
+extern crate hagane_simd;
+
+use hagane_simd::*;
+
+#[test]
+fn test_sort_lanes() {
+  assert_eq!(sort_lanes(int4(3, -1, 2, 0)), int4(-1, 0, 2, 3));
+  assert_eq!(sort_lanes_desc(int4(3, -1, 2, 0)), int4(3, 2, 0, -1));
+  assert_eq!(sort_lanes(float8(5.0, 1.0, -2.0, 1.0, 9.0, 0.0, -7.5, 3.0)), float8(-7.5, -2.0, 0.0, 1.0, 1.0, 3.0, 5.0, 9.0));
+  assert_eq!(sort_lanes_desc(uchar8(5, 1, 2, 1, 9, 0, 7, 3)), uchar8(9, 7, 5, 3, 2, 1, 1, 0));
+
+  let x = sort_lanes(float4(2.0, std::f32::NAN, 1.0, 0.0));
+  assert_eq!(x.into_iter().filter(|x| x.is_nan()).count(), 1);
+}
+
+#[test]
+fn test_sort_lanes_by_key() {
+  let (keys, values) = sort_lanes_by_key(float4(0.5, -1.0, 3.0, 0.0), ulong4(0, 1, 2, 3));
+
+  assert_eq!(keys, float4(-1.0, 0.0, 0.5, 3.0));
+  assert_eq!(values, ulong4(1, 3, 0, 2));
+}
+
+#[test]
+fn test_merge_sorted() {
+  let (lo, hi) = merge_sorted(int4(1, 4, 6, 8), int4(2, 3, 5, 9));
+
+  assert_eq!(lo, int4(1, 2, 3, 4));
+  assert_eq!(hi, int4(5, 6, 8, 9));
+}
+
+quickcheck! {
+  fn sort_lanes_matches_slice_sort(x: Vec<i16>) -> bool {
+    let x = x.into_iter().chain(0 ..).take(16).collect::<short16>();
+    let mut expected = x.as_ref().to_vec();
+    expected.sort();
+
+    return sort_lanes(x).as_ref().to_vec() == expected;
+  }
+
+  fn sort_lanes_by_key_permutes_values(x: Vec<u32>) -> bool {
+    let keys = x.into_iter().chain(0 ..).take(8).collect::<uint8>();
+    let (sorted, indices) = sort_lanes_by_key(keys, int8(0, 1, 2, 3, 4, 5, 6, 7));
+
+    return (0 .. 8).all(|i| keys[indices[i] as usize] == sorted[i]) && (1 .. 8).all(|i| sorted[i - 1] <= sorted[i]);
+  }
+
+  fn merge_sorted_matches_slice_sort(x: Vec<i32>, y: Vec<i32>) -> bool {
+    let x = sort_lanes(x.into_iter().chain(0 ..).take(8).collect::<int8>());
+    let y = sort_lanes(y.into_iter().chain(0 ..).take(8).collect::<int8>());
+    let (lo, hi) = merge_sorted(x, y);
+
+    let mut expected = x.as_ref().to_vec();
+    expected.extend(y.as_ref().to_vec());
+    expected.sort();
+
+    return lo.as_ref().to_vec() == &expected[.. 8] && hi.as_ref().to_vec() == &expected[8 ..];
+  }
+}